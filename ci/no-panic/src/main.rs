@@ -0,0 +1,31 @@
+//! Link-time check that the core functions of `r0` can not panic.
+//!
+//! `#[no_panic]` turns any panic path left in the annotated functions into a link error, so this
+//! only has to build, in release mode: `cargo build --release`.
+
+use no_panic::no_panic;
+
+#[no_panic]
+unsafe fn init_data(sdata: *mut u32, edata: *mut u32, sidata: *const u32) -> usize {
+    r0::init_data(sdata, edata, sidata)
+}
+
+#[no_panic]
+unsafe fn zero_bss(sbss: *mut u32, ebss: *mut u32) -> usize {
+    r0::zero_bss(sbss, ebss)
+}
+
+fn main() {
+    let lma = [1u32, 2, 3, 4];
+    let mut data = [0u32; 4];
+    let mut bss = [!0u32; 4];
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data(sdata, sdata.add(data.len()), lma.as_ptr());
+        let sbss = bss.as_mut_ptr();
+        zero_bss(sbss, sbss.add(bss.len()));
+    }
+
+    assert_eq!(data, lma);
+    assert_eq!(bss, [0; 4]);
+}
@@ -62,9 +62,58 @@
 //! }
 //! ```
 //!
+//! The [`init!`] macro expands to exactly this code.
+//!
+//! [`init!`]: macro.init.html
+//!
+//! # Optional features
+//!
+//! - `wide-words` (enabled by default): [`Word`] is implemented for `u64` and `u128`, and the
+//!   [`init_data64`] and [`zero_bss64`] wrappers for 64-bit targets are available. Targets on
+//!   which 64- and 128-bit accesses are slow can disable this feature to turn accidental uses of
+//!   these word types into compile errors.
+//! - `log`: [`init_data`] and [`zero_bss`] report the base address and size of the region they
+//!   processed with `log::debug!`.
+//! - `defmt`: Same as `log`, but using `defmt::debug!`. As `defmt` needs a global logger provided
+//!   by the target, this feature can not be used in host tests.
+//! - `allow-memset`: [`zero_bss`] uses plain instead of volatile writes, so the compiler may lower
+//!   its loop to a call to `memset`. This is faster and smaller, but requires the target to
+//!   provide a `memset` symbol that works before `.bss` and `.data` are initialized (as the one in
+//!   `compiler_builtins` does).
+//! - `cortex-m-asm`: On ARM targets (`target_arch = "arm"`, which covers all Cortex-M profiles),
+//!   [`zero_bss`] zeroes sections whose word type is at least 4-byte aligned with a hand-written
+//!   loop of two-register `STM` burst stores. Being inline assembly, these stores can be neither
+//!   elided nor merged, like the volatile writes of the portable loop, which is still used on all
+//!   other targets and for narrower word types. This feature requires Rust 1.59.0.
+//! - `debug-prefill`: [`zero_bss`] stamps the region with [`PREFILL_PATTERN`] before zeroing it,
+//!   so that a boot interrupted in between leaves a recognizable signature in RAM.
+//! - `inline`: Every public function of this crate is marked `#[inline(always)]`, so that a reset
+//!   handler calling them once can be optimized as a whole, without separate function symbols.
+//! - `cortex-m-rt`: On ARM targets, [`run_init`] initializes `.bss` and `.data` using the symbols
+//!   defined by the `cortex-m-rt` linker script.
+//!
+//! Apart from `wide-words`, none of these features is enabled by default. When disabled, they add
+//! no code and no dependencies.
+//!
+//! [`PREFILL_PATTERN`]: constant.PREFILL_PATTERN.html
+//! [`Word`]: trait.Word.html
+//! [`init_data`]: fn.init_data.html
+//! [`init_data64`]: fn.init_data64.html
+//! [`run_init`]: fn.run_init.html
+//! [`zero_bss`]: fn.zero_bss.html
+//! [`zero_bss64`]: fn.zero_bss64.html
+//!
+//! # Miri
+//!
+//! When run under [Miri], [`init_data`] and [`zero_bss`] access the sections through slices with
+//! ordinary reads and writes instead of walking them with raw pointers, so that unit tests of
+//! startup code can be checked for undefined behavior with `cargo +nightly miri test`.
+//!
+//! [Miri]: https://github.com/rust-lang/miri
+//!
 //! # Minimum Supported Rust Version (MSRV)
 //!
-//! The MSRV of this release is Rust 1.31.0
+//! The MSRV of this release is Rust 1.57.0
 
 #![deny(warnings)]
 #![no_std]
@@ -73,8 +122,10 @@
 #[cfg(test)]
 mod test;
 
-use core::sync::atomic::{compiler_fence, Ordering};
-use core::{mem, ptr};
+use core::marker::PhantomData;
+use core::ops::{BitXor, Range};
+use core::sync::atomic::{compiler_fence, fence, Ordering};
+use core::{cmp, mem, ptr, slice};
 
 mod sealed {
     pub trait Sealed {}
@@ -83,28 +134,334 @@ mod sealed {
 /// Trait for machine word types.
 ///
 /// This trait is implemented by unsigned integers representing common machine
-/// word sizes. It can not be implemented by the user.
+/// word sizes, and by the pointer-sized `usize` and `isize`. It can not be
+/// implemented by the user. The `u64` and `u128` implementations require the
+/// `wide-words` feature, which is enabled by default.
 ///
 /// Types implementing this trait can be used by the [`init_data`] and
-/// [`zero_bss`] functions. For that to be sound, all bit patterns need to be
-/// valid for the type, the type must implement `Copy`, and the type must not
-/// be zero-sized.
+/// [`zero_bss`] functions.
+///
+/// Words wider than the data bus of the target are copied and zeroed with
+/// several instructions each. On 8-bit (e.g. AVR) and 16-bit (e.g. MSP430)
+/// targets, prefer `u8` or `u16`, for which [`init_data8`], [`zero_bss8`],
+/// [`init_data16`] and [`zero_bss16`] are provided.
+///
+/// # Safety
+///
+/// All bit patterns need to be valid for the type, the type must implement
+/// `Copy`, and the type must not be zero-sized.
 ///
 /// [`init_data`]: fn.init_data.html
+/// [`init_data8`]: fn.init_data8.html
+/// [`init_data16`]: fn.init_data16.html
 /// [`zero_bss`]: fn.zero_bss.html
-pub unsafe trait Word: sealed::Sealed + Copy {}
+/// [`zero_bss8`]: fn.zero_bss8.html
+/// [`zero_bss16`]: fn.zero_bss16.html
+pub unsafe trait Word: sealed::Sealed + Copy + BitXor<Output = Self> {
+    /// The word with all its bits cleared.
+    const ZERO: Self;
+
+    /// Reverses the byte order of the word.
+    fn swap_bytes(self) -> Self;
+}
 
 impl sealed::Sealed for u8 {}
 impl sealed::Sealed for u16 {}
 impl sealed::Sealed for u32 {}
+#[cfg(feature = "wide-words")]
 impl sealed::Sealed for u64 {}
+#[cfg(feature = "wide-words")]
 impl sealed::Sealed for u128 {}
+impl sealed::Sealed for usize {}
+impl sealed::Sealed for isize {}
+
+macro_rules! impl_word {
+    ($($ty:ty),*) => {
+        $(
+            unsafe impl Word for $ty {
+                const ZERO: Self = 0;
+
+                fn swap_bytes(self) -> Self {
+                    <$ty>::swap_bytes(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_word!(u8, u16, u32, usize, isize);
+#[cfg(feature = "wide-words")]
+impl_word!(u64, u128);
+
+/// Forces a compile-time check that `T` is not zero-sized when `OK` is used.
+///
+/// Every `Word` implementation upholds this already; the check is a second line of defense
+/// against a future implementation that does not.
+struct AssertNotZeroSized<T>(PhantomData<T>);
+
+impl<T> AssertNotZeroSized<T> {
+    const OK: () = assert!(mem::size_of::<T>() != 0, "`Word`s must not be zero-sized");
+}
+
+/// Returns the length, in words, of the `start -> end` region.
+///
+/// This is `end.offset_from(start)`, factored out so that the other functions of this crate
+/// measure regions the same way everywhere, without casting the pointers to integers. It is not a
+/// `const fn` because `offset_from` is only `const` from Rust 1.65, above this crate's MSRV.
+///
+/// # Safety
+///
+/// - `end >= start`.
+/// - `start` and `end` must be `T` aligned, and point into (or one past the end of) the same
+///   region.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn region_words<T>(start: *const T, end: *const T) -> usize
+where
+    T: Word,
+{
+    debug_assert!(end >= start, "`end` is below `start`");
+
+    end.offset_from(start) as usize
+}
+
+/// Returns the length, in bytes, of the `start -> end` region, computed with [`region_words`].
+///
+/// [`region_words`]: fn.region_words.html
+unsafe fn region_bytes<T>(start: *const T, end: *const T) -> usize {
+    region_words(start as *const u8, end as *const u8)
+}
+
+/// Logs the outcome of an initialization routine when the `log` or `defmt` feature is enabled.
+#[cfg_attr(not(any(feature = "log", feature = "defmt")), allow(unused_variables))]
+fn report<T>(what: &str, start: *const T, words: usize) {
+    #[cfg(feature = "log")]
+    log::debug!(
+        "r0: {} at {:p} ({} bytes)",
+        what,
+        start,
+        words * mem::size_of::<T>()
+    );
+
+    #[cfg(feature = "defmt")]
+    defmt::debug!(
+        "r0: {=str} at {=usize:#x} ({=usize} bytes)",
+        what,
+        start as usize,
+        words * mem::size_of::<T>()
+    );
+}
+
+/// Returns `true` if `p` is suitably aligned for `T`.
+fn is_aligned<T>(p: *const T) -> bool {
+    p as usize & (mem::align_of::<T>() - 1) == 0
+}
+
+/// A memory region, such as a section delimited by a pair of linker symbols.
+///
+/// Bundling the boundaries of a section into a single value makes it harder to mix up the
+/// pointers passed to the initialization routines. For example:
+///
+/// ```no_run
+/// # use r0::Region;
+/// unsafe fn before_main() {
+///     extern "C" {
+///         static mut _sbss: u32;
+///         static mut _ebss: u32;
+///
+///         static mut _sdata: u32;
+///         static mut _edata: u32;
+///
+///         static _sidata: u32;
+///     }
+///
+///     Region::from_symbols(&mut _sbss, &mut _ebss).zero();
+///     Region::from_symbols(&mut _sdata, &mut _edata).init_from(&_sidata);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Region<T> {
+    start: *mut T,
+    end: *mut T,
+}
+
+impl<T> Region<T>
+where
+    T: Word,
+{
+    /// Creates a region from its start and its open/non-inclusive end.
+    ///
+    /// # Safety
+    ///
+    /// - `end >= start`.
+    /// - `start` and `end` must be `T` aligned.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub unsafe fn from_symbols(start: *mut T, end: *mut T) -> Self {
+        debug_assert!(is_aligned(start), "`start` is not aligned");
+        debug_assert!(is_aligned(end), "`end` is not aligned");
+        debug_assert!(end >= start, "`end` is below `start`");
+
+        Region { start, end }
+    }
+
+    /// Returns a pointer to the start of the region.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn start(&self) -> *mut T {
+        self.start
+    }
+
+    /// Returns a pointer to the open/non-inclusive end of the region.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn end(&self) -> *mut T {
+        self.end
+    }
+
+    /// Returns an iterator over the addresses of the words of the region, in ascending order.
+    ///
+    /// This is for custom per-word passes, e.g. stamping a pattern or checking the contents of
+    /// the region. The iterator stops before `end`, and is empty for an empty region.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn addresses(&self) -> impl Iterator<Item = *mut T> {
+        // NOTE(unsafe) `from_symbols` checked the boundaries of the region
+        let (start, words) = (self.start, unsafe { region_words(self.start, self.end) });
+        (0..words).map(move |i| start.wrapping_add(i))
+    }
+
+    /// Zeroes the region, like [`zero_bss`].
+    ///
+    /// [`zero_bss`]: fn.zero_bss.html
+    ///
+    /// # Safety
+    ///
+    /// - The region must be valid for writes.
+    /// - Nothing may access the region while it is being zeroed.
+    ///
+    /// # Returns
+    ///
+    /// The number of words (of type `T`) that were zeroed.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub unsafe fn zero(&self) -> usize {
+        zero_bss_with(self.start, self.end, |_| {})
+    }
+
+    /// Initializes the region by copying it from `lma`, like [`init_data`].
+    ///
+    /// [`init_data`]: fn.init_data.html
+    ///
+    /// # Safety
+    ///
+    /// - The region must be valid for writes.
+    /// - Nothing may access the region while it is being initialized.
+    /// - `lma` must be `T` aligned, and valid for reads of as many words as the region holds.
+    /// - The region must not overlap with the `lma -> ...` region.
+    ///
+    /// # Returns
+    ///
+    /// The number of words (of type `T`) that were copied.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub unsafe fn init_from(&self, lma: *const T) -> usize {
+        let words = region_words(self.start, self.end);
+        copy_region(self.start, lma, words);
 
-unsafe impl Word for u8 {}
-unsafe impl Word for u16 {}
-unsafe impl Word for u32 {}
-unsafe impl Word for u64 {}
-unsafe impl Word for u128 {}
+        words
+    }
+}
+
+/// The native machine word of the target.
+///
+/// This is `usize`, so it is as wide as a pointer: 16 bits on MSP430, 32 bits on Cortex-M and
+/// RV32, 64 bits on RV64 and AArch64. See [`init_data_native`] and [`zero_bss_native`].
+///
+/// [`init_data_native`]: fn.init_data_native.html
+/// [`zero_bss_native`]: fn.zero_bss_native.html
+pub type NativeWord = usize;
+
+/// Returns the bytes making up `word`, in memory order.
+fn bytes_of<T>(word: &T) -> &[u8]
+where
+    T: Word,
+{
+    // NOTE(unsafe) `Word`s have no padding bytes
+    unsafe { slice::from_raw_parts(word as *const T as *const u8, mem::size_of::<T>()) }
+}
+
+/// Word accessors used by the routines that test and verify memory.
+///
+/// The public functions always use `Volatile`; the unit tests substitute their own accessors to
+/// simulate faulty memory.
+trait Access {
+    unsafe fn read<T>(&mut self, src: *const T) -> T
+    where
+        T: Word;
+
+    unsafe fn write<T>(&mut self, dst: *mut T, value: T)
+    where
+        T: Word;
+}
+
+/// Volatile accesses to real memory.
+struct Volatile;
+
+impl Access for Volatile {
+    unsafe fn read<T>(&mut self, src: *const T) -> T
+    where
+        T: Word,
+    {
+        ptr::read_volatile(src)
+    }
+
+    unsafe fn write<T>(&mut self, dst: *mut T, value: T)
+    where
+        T: Word,
+    {
+        ptr::write_volatile(dst, value)
+    }
+}
+
+/// Copies `words` words from `src` to `dst`.
+///
+/// This is the copy loop behind [`init_data`], for regions that are not a `.data` section, such
+/// as configuration blobs or calibration tables that have to be moved from flash to RAM.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// - `dst`: Pointer to the start of the destination region.
+/// - `src`: Pointer to the start of the source region.
+/// - `words`: Length of both regions, in words of type `T`.
+///
+/// # Safety
+///
+/// - `dst` must be valid for writes, and `src` valid for reads, of `words` words.
+/// - The `dst -> ...` region must not overlap with the `src -> ...` region.
+/// - `dst` and `src` must be `T` aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn copy_region<T>(dst: *mut T, src: *const T, words: usize)
+where
+    T: Word,
+{
+    debug_assert!(is_aligned(dst), "`dst` is not aligned");
+    debug_assert!(is_aligned(src), "`src` is not aligned");
+    debug_assert!(
+        !overlaps(dst, src, words * mem::size_of::<T>()),
+        "`dst -> ...` overlaps `src -> ...`"
+    );
+
+    // NOTE(miri) copy through slices, so that Miri checks the accesses against a single borrow
+    // of each region
+    #[cfg(miri)]
+    slice::from_raw_parts_mut(dst, words).copy_from_slice(slice::from_raw_parts(src, words));
+
+    #[cfg(not(miri))]
+    for i in 0..words {
+        ptr::write(dst.add(i), ptr::read(src.add(i)));
+    }
+
+    // Ensure that any accesses of `static`s are not reordered before the region is initialized.
+    // We use `SeqCst`, because `Acquire` only prevents later accesses from being reordered
+    // before *reads*, but this function only *writes* to the locations.
+    compiler_fence(Ordering::SeqCst);
+}
 
 /// Initializes the `.data` section by copying it from the location indicated
 /// by `sidata`.
@@ -125,50 +482,4178 @@ unsafe impl Word for u128 {}
 /// - The `sdata -> edata` region must not overlap with the `sidata -> ...`
 ///   region.
 /// - `sdata`, `edata` and `sidata` must be `T` aligned.
-pub unsafe fn init_data<T>(mut sdata: *mut T, edata: *mut T, mut sidata: *const T)
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were copied.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data<T>(sdata: *mut T, edata: *mut T, sidata: *const T) -> usize
+where
+    T: Word,
+{
+    let words = init_data_silent(sdata, edata, sidata);
+    report("initialized .data", sdata, words);
+
+    words
+}
+
+/// Initializes the `.data` section like [`init_data`], without reporting it.
+///
+/// This lets the functions that initialize one section in several pieces report it once.
+///
+/// [`init_data`]: fn.init_data.html
+unsafe fn init_data_silent<T>(sdata: *mut T, edata: *mut T, sidata: *const T) -> usize
+where
+    T: Word,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = AssertNotZeroSized::<T>::OK;
+
+    debug_assert!(is_aligned(sdata), "`sdata` is not aligned");
+    debug_assert!(is_aligned(edata), "`edata` is not aligned");
+    debug_assert!(is_aligned(sidata), "`sidata` is not aligned");
+    debug_assert!(edata >= sdata, "`edata` is below `sdata`");
+    debug_assert!(
+        !overlaps(sdata, sidata, region_bytes(sdata, edata)),
+        "`sdata -> edata` overlaps `sidata -> ...`"
+    );
+
+    let words = region_words(sdata, edata);
+    copy_region(sdata, sidata, words);
+
+    words
+}
+
+/// Initializes the `.data` section with a single `copy_nonoverlapping` call.
+///
+/// This is equivalent to [`init_data`], but instead of a word-by-word copy loop it hands the
+/// whole region to [`core::ptr::copy_nonoverlapping`], which compiles down to a `memcpy` even at
+/// low optimization levels. The target must therefore provide a `memcpy` symbol.
+///
+/// [`init_data`]: fn.init_data.html
+/// [`core::ptr::copy_nonoverlapping`]: https://doc.rust-lang.org/core/ptr/fn.copy_nonoverlapping.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were copied.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_fast<T>(sdata: *mut T, edata: *mut T, sidata: *const T) -> usize
+where
+    T: Word,
+{
+    let words = region_words(sdata, edata);
+    ptr::copy_nonoverlapping(sidata, sdata, words);
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+
+    words
+}
+
+/// Error returned by the checked initialization functions when a precondition is violated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InitError {
+    /// One of the pointers is not aligned to the word type.
+    Unaligned,
+    /// The end of a region lies below its start.
+    BadRange,
+    /// The source and destination regions of a copy overlap.
+    Overlap,
+}
+
+impl InitError {
+    /// Returns a short description of the error.
+    ///
+    /// This lets a diagnostic be printed, e.g. over semihosting, without pulling in `core::fmt`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            InitError::Unaligned => "pointer is not aligned",
+            InitError::BadRange => "region end is below its start",
+            InitError::Overlap => "source and destination overlap",
+        }
+    }
+
+    /// Returns a numeric code for the error, for the most compact diagnostics.
+    ///
+    /// The codes are stable: `1` for [`Unaligned`], `2` for [`BadRange`] and `3` for [`Overlap`].
+    /// `0` is never returned, so it can stand for success.
+    ///
+    /// [`Unaligned`]: enum.InitError.html#variant.Unaligned
+    /// [`BadRange`]: enum.InitError.html#variant.BadRange
+    /// [`Overlap`]: enum.InitError.html#variant.Overlap
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub const fn code(&self) -> u8 {
+        match self {
+            InitError::Unaligned => 1,
+            InitError::BadRange => 2,
+            InitError::Overlap => 3,
+        }
+    }
+}
+
+/// Returns `true` if the `len` bytes long regions starting at `a` and `b` overlap.
+fn overlaps<T>(a: *const T, b: *const T, len: usize) -> bool {
+    let (a, b) = (a as usize, b as usize);
+    len != 0 && a < b + len && b < a + len
+}
+
+/// Returns `true` if the `a_len` bytes long region starting at `a` overlaps the `b_len` bytes long
+/// region starting at `b`.
+fn overlaps_len<T>(a: *const T, a_len: usize, b: *const T, b_len: usize) -> bool {
+    let (a, b) = (a as usize, b as usize);
+    a_len != 0 && b_len != 0 && a < b + b_len && b < a + a_len
+}
+
+/// Checks the preconditions of `init_data` that can be checked at runtime.
+fn check_data<T>(sdata: *mut T, edata: *mut T, sidata: *const T) -> Result<(), InitError> {
+    if !is_aligned(sdata) || !is_aligned(edata) || !is_aligned(sidata) {
+        return Err(InitError::Unaligned);
+    }
+
+    if edata < sdata {
+        return Err(InitError::BadRange);
+    }
+
+    // NOTE(unsafe) `edata >= sdata` was checked above
+    if overlaps(sdata, sidata, unsafe { region_bytes(sdata, edata) }) {
+        return Err(InitError::Overlap);
+    }
+
+    Ok(())
+}
+
+/// Checks the preconditions of `zero_bss` that can be checked at runtime.
+fn check_bss<T>(sbss: *mut T, ebss: *mut T) -> Result<(), InitError> {
+    if !is_aligned(sbss) || !is_aligned(ebss) {
+        return Err(InitError::Unaligned);
+    }
+
+    if ebss < sbss {
+        return Err(InitError::BadRange);
+    }
+
+    Ok(())
+}
+
+/// Initializes the `.data` section like [`init_data`], after validating its arguments.
+///
+/// Instead of relying on the caller to uphold the alignment, ordering and non-overlap
+/// requirements of [`init_data`], this function checks them and returns an error without
+/// touching memory if any of them is violated.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - The `sdata -> edata` region must be valid for writes, and the equally sized region starting
+///   at `sidata` must be valid for reads.
+///
+/// # Errors
+///
+/// - [`InitError::Unaligned`] if `sdata`, `edata` or `sidata` is not `T` aligned.
+/// - [`InitError::BadRange`] if `edata < sdata`.
+/// - [`InitError::Overlap`] if the `sdata -> edata` region overlaps the `sidata -> ...` region.
+///
+/// [`InitError::Unaligned`]: enum.InitError.html#variant.Unaligned
+/// [`InitError::BadRange`]: enum.InitError.html#variant.BadRange
+/// [`InitError::Overlap`]: enum.InitError.html#variant.Overlap
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_checked<T>(
+    sdata: *mut T,
+    edata: *mut T,
+    sidata: *const T,
+) -> Result<(), InitError>
+where
+    T: Word,
+{
+    check_data(sdata, edata, sidata)?;
+    init_data(sdata, edata, sidata);
+
+    Ok(())
+}
+
+/// Initializes the `.data` section like [`init_data`], returning a checksum of the copied data.
+///
+/// The checksum is the wrapping 32-bit sum of all the bytes of the section. It is computed in the
+/// same pass as the copy, which is cheaper than re-reading slow flash afterwards, and can be
+/// compared against a checkword placed in flash by the build process.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_checksummed<T>(
+    mut sdata: *mut T,
+    edata: *mut T,
+    mut sidata: *const T,
+) -> u32
 where
     T: Word,
 {
+    let mut sum = 0u32;
     while sdata < edata {
-        ptr::write(sdata, ptr::read(sidata));
+        let word = ptr::read(sidata);
+        ptr::write(sdata, word);
+        sum = bytes_of(&word)
+            .iter()
+            .fold(sum, |sum, &byte| sum.wrapping_add(u32::from(byte)));
         sdata = sdata.offset(1);
         sidata = sidata.offset(1);
     }
 
-    // Ensure that any accesses of `static`s are not reordered before the `.data` section is
-    // initialized.
-    // We use `SeqCst`, because `Acquire` only prevents later accesses from being reordered before
-    // *reads*, but this method only *writes* to the locations.
+    // See `copy_region`
     compiler_fence(Ordering::SeqCst);
+
+    sum
 }
 
-/// Zeroes the `.bss` section.
+/// Initializes the `.data` section like [`init_data`], allowing the section and its LMA to
+/// overlap.
+///
+/// This is only needed for unusual memory maps, e.g. when RAM is remapped so that the `.data`
+/// section partially overlaps its LMA. Like `memmove`, the words are copied backward when the
+/// section starts above its LMA, so that no word of the LMA is overwritten before it is read,
+/// and forward otherwise.
+///
+/// [`init_data`]: fn.init_data.html
 ///
 /// # Arguments
 ///
-/// - `sbss`: Pointer to the start of the `.bss` section in RAM.
-/// - `ebss`: Pointer to the open/non-inclusive end of the `.bss` section in
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `edata >= sdata`.
+/// - `sdata`, `edata` and `sidata` must be `T` aligned.
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were copied.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_overlapping<T>(sdata: *mut T, edata: *mut T, sidata: *const T) -> usize
+where
+    T: Word,
+{
+    let words = region_words(sdata, edata);
+    if (sdata as usize) > sidata as usize {
+        for i in (0..words).rev() {
+            ptr::write(sdata.add(i), ptr::read(sidata.add(i)));
+        }
+    } else {
+        for i in 0..words {
+            ptr::write(sdata.add(i), ptr::read(sidata.add(i)));
+        }
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+
+    words
+}
+
+/// Initializes the `.data` section with values computed by `gen`, instead of copying them from
+/// an LMA.
+///
+/// This is for initial data that is generated by an algorithm, e.g. a sine table, rather than
+/// stored contiguously in flash. `gen` is called with the index of every word of the section, in
+/// ascending order. Unlike [`init_with`], which is meant for arbitrary regions and uses volatile
+/// writes, this has the semantics of [`init_data`].
+///
+/// [`init_data`]: fn.init_data.html
+/// [`init_with`]: fn.init_with.html
+///
+/// # Arguments
+///
+/// - `sdata`: Pointer to the start of the `.data` section in RAM.
+/// - `edata`: Pointer to the open/non-inclusive end of the `.data` section in
 ///   RAM (the value behind this pointer will not be modified).
-/// - Use `T` to indicate the alignment of the `.bss` section.
+/// - `gen`: Computes the value of a word from its index. It must not access the `.data` section.
 ///
 /// # Safety
 ///
 /// - Must be called exactly once, before the application has started.
-/// - `ebss >= sbss`.
-/// - `sbss` and `ebss` must be `T` aligned.
-pub unsafe fn zero_bss<T>(mut sbss: *mut T, ebss: *mut T)
+/// - `edata >= sdata`.
+/// - `sdata` and `edata` must be `T` aligned.
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were initialized.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_gen<T, F>(sdata: *mut T, edata: *mut T, mut gen: F) -> usize
 where
     T: Word,
+    F: FnMut(usize) -> T,
 {
-    while sbss < ebss {
-        // NOTE(volatile) to prevent this from being transformed into `memclr`
-        ptr::write_volatile(sbss, mem::zeroed());
-        sbss = sbss.offset(1);
+    let words = region_words(sdata, edata);
+    for i in 0..words {
+        ptr::write(sdata.add(i), gen(i));
     }
 
-    // Ensure that any accesses of `static`s are not reordered before the `.bss` section is
-    // initialized.
-    // We use `SeqCst`, because `Acquire` only prevents later accesses from being reordered before
-    // *reads*, but this method only *writes* to the locations.
+    // See `copy_region`
     compiler_fence(Ordering::SeqCst);
+
+    words
+}
+
+/// Initializes the `.data` section like [`init_data`], reversing the byte order of every word.
+///
+/// This is for images whose `.data` LMA was produced with the opposite endianness of the target,
+/// e.g. by a tool that emits big-endian data for a little-endian core. Each word is read from
+/// `sidata`, passed through [`Word::swap_bytes`] and written to `sdata`.
+///
+/// [`init_data`]: fn.init_data.html
+/// [`Word::swap_bytes`]: trait.Word.html#tymethod.swap_bytes
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_bswap<T>(mut sdata: *mut T, edata: *mut T, mut sidata: *const T)
+where
+    T: Word,
+{
+    while sdata < edata {
+        ptr::write(sdata, ptr::read(sidata).swap_bytes());
+        sdata = sdata.offset(1);
+        sidata = sidata.offset(1);
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Initializes the `.data` section like [`init_data`], then verifies the copy against a second
+/// read of the LMA.
+///
+/// On unreliable flash, a read disturb or a brown-out during boot can corrupt the values copied
+/// into RAM. After the copy, this function reads every word of `sidata` again and compares it
+/// with the word that landed in RAM. All the reads of this second pass are volatile.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+///
+/// # Errors
+///
+/// Returns the address of the first LMA word that does not match its copy in RAM.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_verified<T>(
+    sdata: *mut T,
+    edata: *mut T,
+    sidata: *const T,
+) -> Result<(), *const T>
+where
+    T: Word,
+{
+    init_verified(&mut Volatile, sdata, edata, sidata)
+}
+
+unsafe fn init_verified<A, T>(
+    flash: &mut A,
+    sdata: *mut T,
+    edata: *mut T,
+    sidata: *const T,
+) -> Result<(), *const T>
+where
+    A: Access,
+    T: Word,
+{
+    let words = region_words(sdata, edata);
+    for i in 0..words {
+        ptr::write(sdata.add(i), flash.read(sidata.add(i)));
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+
+    for i in 0..words {
+        let (lma, vma) = (flash.read(sidata.add(i)), ptr::read_volatile(sdata.add(i)));
+        if bytes_of(&lma) != bytes_of(&vma) {
+            return Err(sidata.add(i));
+        }
+    }
+
+    Ok(())
+}
+
+/// Initializes the `.data` section like [`init_data`], returning the XOR of all the copied words.
+///
+/// This is the cheapest possible integrity check: the fold is computed in the same pass as the
+/// copy, and can be compared against a checkword placed in flash by the build process.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_xor<T>(mut sdata: *mut T, edata: *mut T, mut sidata: *const T) -> T
+where
+    T: Word,
+{
+    let mut fold = T::ZERO;
+    while sdata < edata {
+        let word = ptr::read(sidata);
+        ptr::write(sdata, word);
+        fold = fold ^ word;
+        sdata = sdata.offset(1);
+        sidata = sidata.offset(1);
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+
+    fold
+}
+
+/// Initializes the `.data` section like [`init_data`], reading every word back right after
+/// writing it.
+///
+/// This catches gross RAM faults, such as stuck bits, during the copy itself. Both the writes to
+/// and the read backs from RAM are volatile. On ECC RAM, the read backs also give the ECC logic a
+/// chance to flag corrected errors in its status registers, which this crate does not access.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+///
+/// # Errors
+///
+/// Returns the address of the first word that did not read back as written. The words after it
+/// are not copied.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_readback<T>(
+    sdata: *mut T,
+    edata: *mut T,
+    sidata: *const T,
+) -> Result<(), *mut T>
+where
+    T: Word,
+{
+    init_readback(&mut Volatile, sdata, edata, sidata)
+}
+
+unsafe fn init_readback<A, T>(
+    ram: &mut A,
+    mut sdata: *mut T,
+    edata: *mut T,
+    mut sidata: *const T,
+) -> Result<(), *mut T>
+where
+    A: Access,
+    T: Word,
+{
+    while sdata < edata {
+        let word = ptr::read(sidata);
+        ram.write(sdata, word);
+        if bytes_of(&ram.read(sdata)) != bytes_of(&word) {
+            return Err(sdata);
+        }
+        sdata = sdata.offset(1);
+        sidata = sidata.offset(1);
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+
+    Ok(())
+}
+
+/// Summary of a [`init_data_reported`] call.
+///
+/// [`init_data_reported`]: fn.init_data_reported.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DataInitReport {
+    /// The number of words (of type `T`) that were copied.
+    pub words: usize,
+    /// Fletcher-32 checksum of the copied data.
+    pub checksum: u32,
+}
+
+/// Running Fletcher-32 checksum over a byte stream split into little-endian 16-bit halfwords.
+struct Fletcher32 {
+    sum1: u32,
+    sum2: u32,
+    low: Option<u8>,
+}
+
+impl Fletcher32 {
+    fn new() -> Self {
+        Fletcher32 {
+            sum1: 0,
+            sum2: 0,
+            low: None,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            match self.low.take() {
+                None => self.low = Some(byte),
+                Some(low) => self.add(u32::from(low) | u32::from(byte) << 8),
+            }
+        }
+    }
+
+    fn add(&mut self, halfword: u32) {
+        self.sum1 = (self.sum1 + halfword) % 0xFFFF;
+        self.sum2 = (self.sum2 + self.sum1) % 0xFFFF;
+    }
+
+    fn finish(mut self) -> u32 {
+        // An odd trailing byte is padded with a zero high byte
+        if let Some(low) = self.low.take() {
+            self.add(u32::from(low));
+        }
+
+        self.sum2 << 16 | self.sum1
+    }
+}
+
+/// Initializes the `.data` section like [`init_data`], returning the number of copied words and
+/// a checksum of the copied data.
+///
+/// The checksum is a Fletcher-32 over the bytes of the section, in memory order, taken as
+/// little-endian 16-bit halfwords; an odd trailing byte is padded with a zero byte. Both values
+/// are computed in the same pass as the copy, so slow flash is only traversed once.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_reported<T>(
+    mut sdata: *mut T,
+    edata: *mut T,
+    mut sidata: *const T,
+) -> DataInitReport
+where
+    T: Word,
+{
+    let mut fletcher = Fletcher32::new();
+    let mut words = 0;
+    while sdata < edata {
+        let word = ptr::read(sidata);
+        ptr::write(sdata, word);
+        fletcher.update(bytes_of(&word));
+        sdata = sdata.offset(1);
+        sidata = sidata.offset(1);
+        words += 1;
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+
+    DataInitReport {
+        words,
+        checksum: fletcher.finish(),
+    }
+}
+
+/// Initializes the `.data` section like [`init_data`], reading the LMA with volatile loads.
+///
+/// On some parts the `.data` LMA lives behind a memory-mapped interface, such as QSPI flash in
+/// memory-mapped mode, where every read must actually reach the bus; the compiler, however,
+/// assumes ordinary memory and may merge, widen or reorder the loads of [`init_data`]. This
+/// function reads each source word with `read_volatile`, mirroring how [`zero_bss`] uses
+/// volatile writes, while still writing RAM normally.
+///
+/// [`init_data`]: fn.init_data.html
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_volatile_src<T>(mut sdata: *mut T, edata: *mut T, mut sidata: *const T)
+where
+    T: Word,
+{
+    while sdata < edata {
+        ptr::write(sdata, ptr::read_volatile(sidata));
+        sdata = sdata.offset(1);
+        sidata = sidata.offset(1);
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Initializes a `.data` section whose length is not a multiple of the word size.
+///
+/// The bulk of the section is copied as 32-bit words; the remaining one to three trailing bytes,
+/// if any, are then copied individually.
+///
+/// # Arguments
+///
+/// - `sdata`: Pointer to the start of the `.data` section in RAM.
+/// - `edata`: Pointer to the open/non-inclusive end of the `.data` section in
+///   RAM (the value behind this pointer will not be modified).
+/// - `sidata`: `.data` section Load Memory Address (LMA). Data will be copied
+///   from here.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `edata >= sdata`.
+/// - The `sdata -> edata` region must not overlap with the `sidata -> ...`
+///   region.
+/// - `sdata` and `sidata` must be 4-byte aligned, as the bulk of the section is copied as 32-bit
+///   words. `edata` may have any alignment.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_bytes(sdata: *mut u8, edata: *mut u8, sidata: *const u8) {
+    let wide = region_words(sdata, edata) & !(mem::size_of::<u32>() - 1);
+    let (tail, sitail) = (sdata.add(wide), sidata.add(wide));
+
+    init_data_silent(sdata as *mut u32, tail as *mut u32, sidata as *const u32);
+    init_data_silent(tail, edata, sitail);
+    report("initialized .data", sdata, region_words(sdata, edata));
+}
+
+/// Initializes the `.data` section from an LMA split in two pieces.
+///
+/// On parts with banked or remapped flash, the `.data` LMA can wrap across a bank boundary, so
+/// the image is not contiguous. The first `src_a.1` bytes of the section are copied from
+/// `src_a.0`, and the rest of the section from `src_b`, without any scratch buffer. If the first
+/// piece is at least as long as the section, `src_b` is not read.
+///
+/// # Arguments
+///
+/// - `sdata`: Pointer to the start of the `.data` section in RAM.
+/// - `edata`: Pointer to the open/non-inclusive end of the `.data` section in
+///   RAM (the value behind this pointer will not be modified).
+/// - `src_a`: Pointer to the first piece of the LMA, and its length in bytes.
+/// - `src_b`: Pointer to the second piece of the LMA, which holds the remaining bytes.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `edata >= sdata`.
+/// - `src_a.0` must be valid for reads of `src_a.1` bytes (or of the section length, if smaller),
+///   and `src_b` for reads of the remaining bytes.
+/// - The `sdata -> edata` region must not overlap with either piece. The pointers have no
+///   alignment requirement.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_split(
+    sdata: *mut u8,
+    edata: *mut u8,
+    src_a: (*const u8, usize),
+    src_b: *const u8,
+) {
+    let mid = sdata.add(cmp::min(src_a.1, region_words(sdata, edata)));
+
+    init_data_silent(sdata, mid, src_a.0);
+    init_data_silent(mid, edata, src_b);
+    report("initialized .data", sdata, region_words(sdata, edata));
+}
+
+/// Initializes several `.data`-like sections, e.g. one in SRAM and one in CCM/TCM RAM.
+///
+/// Each entry of `regions` is an `(sdata, edata, sidata)` triple that is handed to
+/// [`init_data`], in order.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - Every entry must satisfy the safety requirements of [`init_data`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_regions<T>(regions: &[(*mut T, *mut T, *const T)])
+where
+    T: Word,
+{
+    for &(sdata, edata, sidata) in regions {
+        init_data(sdata, edata, sidata);
+    }
+}
+
+/// Initializes the `.data` section, given its length instead of its end.
+///
+/// This is [`init_data`] for linker scripts that export the length of the section (e.g.
+/// `_sdata_len`) rather than a symbol at its end.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// - `sdata`: Pointer to the start of the `.data` section in RAM.
+/// - `sidata`: `.data` section Load Memory Address (LMA). Data will be copied
+///   from here.
+/// - `words`: Length of the `.data` section, counted in units of `T` (not bytes).
+/// - Use `T` to indicate the alignment of the `.data` section and its LMA.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - The `sdata -> sdata + words` region must not overlap with the
+///   `sidata -> sidata + words` region.
+/// - `sdata` and `sidata` must be `T` aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_len<T>(sdata: *mut T, sidata: *const T, words: usize)
+where
+    T: Word,
+{
+    init_data(sdata, sdata.add(words), sidata);
+}
+
+/// Initializes the `.data` section from an array, instead of an LMA given by a linker symbol.
+///
+/// This lets the same startup code run in hosted tests and simulations, where the initial data is
+/// a Rust `const` or `static` array, and on the target, where that array lives in flash and
+/// doubles as the LMA. No `_sidata` symbol is needed:
+///
+/// ```no_run
+/// # use r0::init_data_from_const;
+/// static INITIAL: [u32; 4] = [1, 2, 3, 4];
+///
+/// unsafe fn before_main(sdata: *mut u32) {
+///     init_data_from_const(sdata, &INITIAL);
+/// }
+/// ```
+///
+/// # Arguments
+///
+/// - `sdata`: Pointer to the start of the `.data` section in RAM.
+/// - `src`: The initial contents of the `.data` section, `N` words long.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `sdata` must be `T` aligned and valid for writes of `N` words of type `T`.
+/// - The `sdata -> sdata + N` region must not overlap with `src`.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_from_const<T, const N: usize>(sdata: *mut T, src: &[T; N])
+where
+    T: Word,
+{
+    init_data(sdata, sdata.add(N), src.as_ptr());
+}
+
+/// Initializes the `.data` section, given as a `Range`.
+///
+/// This is [`init_data`] with the start and end of the section bundled together, so that call
+/// sites read as `init_data_range(sdata..edata, sidata)` and the two can not be swapped.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// - `data`: The `.data` section in RAM; `data.end` is open/non-inclusive.
+/// - `sidata`: `.data` section Load Memory Address (LMA). Data will be copied
+///   from here.
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were copied.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_range<T>(data: Range<*mut T>, sidata: *const T) -> usize
+where
+    T: Word,
+{
+    init_data(data.start, data.end, sidata)
+}
+
+/// A Virtual Memory Address (VMA): where a section lives in RAM at runtime.
+///
+/// See [`init_data_typed`].
+///
+/// [`init_data_typed`]: fn.init_data_typed.html
+#[derive(Clone, Copy, Debug)]
+pub struct Vma<T>(*mut T);
+
+impl<T> Vma<T> {
+    /// Wraps a pointer to a location in RAM.
+    ///
+    /// # Safety
+    ///
+    /// `p` must be the runtime address of a section boundary, e.g. `&mut _sdata`, and not a load
+    /// address.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub unsafe fn new(p: *mut T) -> Self {
+        Vma(p)
+    }
+
+    /// Returns the wrapped pointer.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn as_ptr(self) -> *mut T {
+        self.0
+    }
+}
+
+/// A Load Memory Address (LMA): where the initial contents of a section are stored, e.g. in
+/// flash.
+///
+/// See [`init_data_typed`].
+///
+/// [`init_data_typed`]: fn.init_data_typed.html
+#[derive(Clone, Copy, Debug)]
+pub struct Lma<T>(*const T);
+
+impl<T> Lma<T> {
+    /// Wraps a pointer to the load address of a section.
+    ///
+    /// # Safety
+    ///
+    /// `p` must be the load address of a section, e.g. `&_sidata`, and not a runtime address.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub unsafe fn new(p: *const T) -> Self {
+        Lma(p)
+    }
+
+    /// Returns the wrapped pointer.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn as_ptr(self) -> *const T {
+        self.0
+    }
+}
+
+/// Initializes the `.data` section, with its boundaries and its LMA told apart by their types.
+///
+/// This is [`init_data`] taking [`Vma`]s for the section and an [`Lma`] for its load address, so
+/// that passing `_sidata` where `_sdata` is expected, or the reverse, does not compile:
+///
+/// ```compile_fail
+/// use r0::{init_data_typed, Lma, Vma};
+///
+/// unsafe fn swapped(sdata: Vma<u32>, edata: Vma<u32>, sidata: Lma<u32>) {
+///     init_data_typed(sidata, edata, sdata);
+/// }
+/// ```
+///
+/// [`Lma`]: struct.Lma.html
+/// [`Vma`]: struct.Vma.html
+/// [`init_data`]: fn.init_data.html
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were copied.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_typed<T>(sdata: Vma<T>, edata: Vma<T>, sidata: Lma<T>) -> usize
+where
+    T: Word,
+{
+    init_data(sdata.as_ptr(), edata.as_ptr(), sidata.as_ptr())
+}
+
+/// Initializes the `.data` section like [`init_data`], returning the number of bytes copied.
+///
+/// This is for boot telemetry that reports sizes in bytes; unlike [`init_data_bytes`], it still
+/// copies whole words of type `T`.
+///
+/// [`init_data`]: fn.init_data.html
+/// [`init_data_bytes`]: fn.init_data_bytes.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+///
+/// # Returns
+///
+/// The number of bytes that were copied.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_bytes_count<T>(sdata: *mut T, edata: *mut T, sidata: *const T) -> usize
+where
+    T: Word,
+{
+    init_data(sdata, edata, sidata) * mem::size_of::<T>()
+}
+
+/// Initializes the `.data` section like [`init_data`], returning a pointer one past the last word
+/// written.
+///
+/// The returned pointer equals `edata` once the copy has run to completion, which lets callers
+/// chaining several initialization steps check their progress.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_ret<T>(sdata: *mut T, edata: *mut T, sidata: *const T) -> *mut T
+where
+    T: Word,
+{
+    sdata.add(init_data(sdata, edata, sidata))
+}
+
+/// Initializes the `.data` section like [`init_data`], then reports the number of words copied.
+///
+/// This is for boot profiling: `report` is called exactly once, after the copy, so code that
+/// correlates the word count with a cycle counter read before and after this call stays out of
+/// the copy loop.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// - `sdata`, `edata`, `sidata`: Same as [`init_data`].
+/// - `report`: Called with the number of words (of type `T`) that were copied. It must not access
+///   the `.data` section.
+///
+/// # Safety
+///
+/// Same as [`init_data`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_profiled<T, F>(sdata: *mut T, edata: *mut T, sidata: *const T, report: F)
+where
+    T: Word,
+    F: FnOnce(usize),
+{
+    report(init_data(sdata, edata, sidata));
+}
+
+/// Zeroes the `.bss` section and initializes the `.data` section, after validating both.
+///
+/// This bundles the usual startup sequence, [`zero_bss`] followed by [`init_data`], behind a
+/// single call. Both regions are checked first, like [`init_data_checked`] does, and nothing is
+/// written if either of them is invalid.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+/// [`init_data`]: fn.init_data.html
+/// [`init_data_checked`]: fn.init_data_checked.html
+///
+/// # Arguments
+///
+/// - `bss`: The `(sbss, ebss)` boundaries of the `.bss` section, as for [`zero_bss`].
+/// - `data`: The `(sdata, edata, sidata)` boundaries and LMA of the `.data` section, as for
+///   [`init_data`].
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - The `sbss -> ebss` and `sdata -> edata` regions must be valid for writes, and the region
+///   starting at `sidata` must be valid for reads of `edata - sdata` bytes.
+///
+/// # Errors
+///
+/// - [`InitError::Unaligned`] if any of the pointers is not 4-byte aligned.
+/// - [`InitError::BadRange`] if `ebss < sbss` or `edata < sdata`.
+/// - [`InitError::Overlap`] if the `sdata -> edata` region overlaps the `sidata -> ...` region,
+///   or if the `sbss -> ebss` region overlaps either of them.
+///
+/// [`InitError::Unaligned`]: enum.InitError.html#variant.Unaligned
+/// [`InitError::BadRange`]: enum.InitError.html#variant.BadRange
+/// [`InitError::Overlap`]: enum.InitError.html#variant.Overlap
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_all(
+    bss: (*mut u32, *mut u32),
+    data: (*mut u32, *mut u32, *const u32),
+) -> Result<(), InitError> {
+    let (sbss, ebss) = bss;
+    let (sdata, edata, sidata) = data;
+
+    check_bss(sbss, ebss)?;
+    check_data(sdata, edata, sidata)?;
+
+    let (bss_len, data_len) = (region_bytes(sbss, ebss), region_bytes(sdata, edata));
+    if overlaps_len(sbss, bss_len, sdata, data_len) || overlaps_len(sbss, bss_len, sidata, data_len)
+    {
+        return Err(InitError::Overlap);
+    }
+
+    zero_bss(sbss, ebss);
+    init_data(sdata, edata, sidata);
+
+    Ok(())
+}
+
+/// Zeroes the `.bss` section.
+///
+/// # Arguments
+///
+/// - `sbss`: Pointer to the start of the `.bss` section in RAM.
+/// - `ebss`: Pointer to the open/non-inclusive end of the `.bss` section in
+///   RAM (the value behind this pointer will not be modified).
+/// - Use `T` to indicate the alignment of the `.bss` section.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `ebss >= sbss`.
+/// - `sbss` and `ebss` must be `T` aligned.
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss<T>(sbss: *mut T, ebss: *mut T) -> usize
+where
+    T: Word,
+{
+    let words = zero_bss_silent(sbss, ebss);
+    report("zeroed .bss", sbss, words);
+
+    words
+}
+
+/// Zeroes the `.bss` section like [`zero_bss`], without reporting it.
+///
+/// This lets the functions that zero one section in several pieces report it once.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+unsafe fn zero_bss_silent<T>(sbss: *mut T, ebss: *mut T) -> usize
+where
+    T: Word,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = AssertNotZeroSized::<T>::OK;
+
+    #[cfg(feature = "debug-prefill")]
+    {
+        let pattern = PREFILL_PATTERN.to_ne_bytes();
+        init_with(sbss as *mut u8, ebss as *mut u8, |i| {
+            pattern[i % pattern.len()]
+        });
+    }
+
+    #[cfg(all(feature = "cortex-m-asm", target_arch = "arm"))]
+    let words = if mem::align_of::<T>() >= 4 {
+        zero_bss_asm(sbss, ebss)
+    } else {
+        zero_bss_set(sbss, ebss)
+    };
+    #[cfg(not(all(feature = "cortex-m-asm", target_arch = "arm")))]
+    let words = zero_bss_set(sbss, ebss);
+
+    words
+}
+
+/// Zeroes the `.bss` section with [`set_region`].
+///
+/// [`set_region`]: fn.set_region.html
+unsafe fn zero_bss_set<T>(sbss: *mut T, ebss: *mut T) -> usize
+where
+    T: Word,
+{
+    debug_assert!(is_aligned(sbss), "`sbss` is not aligned");
+    debug_assert!(is_aligned(ebss), "`ebss` is not aligned");
+    debug_assert!(ebss >= sbss, "`ebss` is below `sbss`");
+
+    // NOTE(volatile) to prevent this from being transformed into `memclr`, unless the user has
+    // told us that the target provides one
+    #[cfg(not(feature = "allow-memset"))]
+    set_region::<T, true>(sbss, ebss, T::ZERO);
+    #[cfg(feature = "allow-memset")]
+    set_region::<T, false>(sbss, ebss, T::ZERO);
+
+    region_words(sbss, ebss)
+}
+
+/// Zeroes a 4-byte aligned `sbss -> ebss` region with `STM` burst stores.
+#[cfg(all(feature = "cortex-m-asm", target_arch = "arm"))]
+unsafe fn zero_bss_asm<T>(sbss: *mut T, ebss: *mut T) -> usize
+where
+    T: Word,
+{
+    use core::arch::asm;
+
+    debug_assert!(is_aligned(sbss), "`sbss` is not aligned");
+    debug_assert!(is_aligned(ebss), "`ebss` is not aligned");
+    debug_assert!(ebss >= sbss, "`ebss` is below `sbss`");
+
+    let words = region_words(sbss, ebss);
+    let pairs = region_bytes(sbss, ebss) / 8;
+    let mut sbss = sbss as *mut u32;
+    if pairs != 0 {
+        // NOTE `STM` with a writeback base and low registers is available in both the ARM and
+        // the Thumb-1 instruction sets, so this also assembles for ARMv6-M
+        asm!(
+            "2:",
+            "stm r0!, {{r2, r3}}",
+            "subs r1, #1",
+            "bne 2b",
+            inout("r0") sbss,
+            inout("r1") pairs => _,
+            in("r2") 0u32,
+            in("r3") 0u32,
+            options(nostack),
+        );
+    }
+    if sbss < ebss as *mut u32 {
+        ptr::write_volatile(sbss, 0);
+    }
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+
+    words
+}
+
+/// Pattern [`zero_bss`] stamps the `.bss` section with, before zeroing it, when the
+/// `debug-prefill` feature is enabled.
+///
+/// The pattern is written in native byte order, so that each 4-byte aligned word of the section
+/// reads as `0xDEADBEEF` in between.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+pub const PREFILL_PATTERN: u32 = 0xDEAD_BEEF;
+
+/// Zeroes the gap between two sections.
+///
+/// Linker scripts often leave alignment padding between sections (e.g. between `.data` and
+/// `.bss`) that neither symbol pair covers. This is the same operation as [`zero_bss`], named
+/// after its intent so that startup code documents itself:
+///
+/// ```no_run
+/// # use r0::zero_gap;
+/// unsafe fn before_main() {
+///     extern "C" {
+///         static mut _edata: u32;
+///         static mut _sbss: u32;
+///     }
+///
+///     zero_gap(&mut _edata, &mut _sbss);
+/// }
+/// ```
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `after`: Pointer to the end of the section preceding the gap.
+/// - `before`: Pointer to the start of the section following the gap (the value behind this
+///   pointer will not be modified).
+/// - Use `T` to indicate the alignment of the gap.
+///
+/// # Safety
+///
+/// - Must be called before the application has started.
+/// - `before >= after`.
+/// - `after` and `before` must be `T` aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_gap<T>(after: *mut T, before: *mut T)
+where
+    T: Word,
+{
+    debug_assert!(before >= after, "`before` is below `after`");
+
+    zero_bss(after, before);
+}
+
+/// Zeroes the `.bss` section, except for a hole that is left untouched.
+///
+/// This is for a `.bss` section that contains a region which must survive a warm reset, e.g.
+/// battery-backed retention RAM. The `sbss -> skip_start` and `skip_end -> ebss` parts of the
+/// section are zeroed like [`zero_bss`].
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `sbss`, `ebss`: Same as [`zero_bss`].
+/// - `skip_start`: Pointer to the start of the hole.
+/// - `skip_end`: Pointer to the open/non-inclusive end of the hole.
+///
+/// # Safety
+///
+/// Same as [`zero_bss`]. In addition, `skip_start` and `skip_end` must be `T` aligned.
+///
+/// # Panics
+///
+/// If the `skip_start -> skip_end` hole does not lie within the `sbss -> ebss` section.
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_except<T>(
+    sbss: *mut T,
+    ebss: *mut T,
+    skip_start: *mut T,
+    skip_end: *mut T,
+) -> usize
+where
+    T: Word,
+{
+    assert!(
+        sbss <= skip_start && skip_start <= skip_end && skip_end <= ebss,
+        "`skip_start -> skip_end` is not within `sbss -> ebss`"
+    );
+
+    let words = zero_bss_silent(sbss, skip_start) + zero_bss_silent(skip_end, ebss);
+    report("zeroed .bss", sbss, words);
+
+    words
+}
+
+/// Zeroes several `.bss` sections, e.g. one per RAM bank.
+///
+/// Each entry of `table` is an `(sbss, ebss)` pair that is handed to [`zero_bss`], in order.
+/// This is the `.bss` counterpart of [`init_data_regions`].
+///
+/// [`zero_bss`]: fn.zero_bss.html
+/// [`init_data_regions`]: fn.init_data_regions.html
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - Every entry must satisfy the safety requirements of [`zero_bss`] for `T = u32`.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_banked(table: &[(*mut u32, *mut u32)]) {
+    for &(sbss, ebss) in table {
+        zero_bss(sbss, ebss);
+    }
+}
+
+/// Zeroes the `.bss` section, given its length instead of its end.
+///
+/// This is [`zero_bss`] for linker scripts that export the length of the section (e.g.
+/// `_sbss_len`) rather than a symbol at its end.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `sbss`: Pointer to the start of the `.bss` section in RAM.
+/// - `words`: Length of the `.bss` section, counted in units of `T` (not bytes).
+/// - Use `T` to indicate the alignment of the `.bss` section.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `sbss` must be `T` aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_len<T>(sbss: *mut T, words: usize)
+where
+    T: Word,
+{
+    zero_bss(sbss, sbss.add(words));
+}
+
+/// Zeroes exactly `N` words, `N` being known at compile time.
+///
+/// For small regions of a fixed size, such as a per-task control block zeroed at creation, the
+/// loop has a constant trip count and no end pointer to compare against, so the compiler can
+/// fully unroll it into a sequence of stores. The stores are still volatile.
+///
+/// # Arguments
+///
+/// - `sbss`: Pointer to the start of the region.
+/// - `N`: Length of the region, counted in units of `T` (not bytes).
+/// - Use `T` to indicate the alignment of the region.
+///
+/// # Safety
+///
+/// - `sbss` must be `T` aligned and valid for writes of `N` words of type `T`.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_n<T, const N: usize>(sbss: *mut T)
+where
+    T: Word,
+{
+    debug_assert!(is_aligned(sbss), "`sbss` is not aligned");
+
+    for i in 0..N {
+        // NOTE(volatile) to prevent this from being transformed into `memclr`
+        ptr::write_volatile(sbss.add(i), T::ZERO);
+    }
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Zeroes the `.bss` section, given as a `Range`.
+///
+/// This is [`zero_bss`] with the start and end of the section bundled together, so that call
+/// sites read as `zero_bss_range(sbss..ebss)` and the two can not be swapped.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `bss`: The `.bss` section in RAM; `bss.end` is open/non-inclusive.
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_range<T>(bss: Range<*mut T>) -> usize
+where
+    T: Word,
+{
+    zero_bss(bss.start, bss.end)
+}
+
+/// Zeroes the `.bss` section like [`zero_bss`], returning the number of bytes zeroed.
+///
+/// This is for boot telemetry that reports sizes in bytes; the section is still zeroed in whole
+/// words of type `T`.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// Same as [`zero_bss`].
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+///
+/// # Returns
+///
+/// The number of bytes that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_bytes<T>(sbss: *mut T, ebss: *mut T) -> usize
+where
+    T: Word,
+{
+    zero_bss(sbss, ebss) * mem::size_of::<T>()
+}
+
+/// Zeroes a slice, like [`zero_bss`].
+///
+/// This is a safe wrapper for host-side tests and simulators, which have slices rather than
+/// linker symbols.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn zero_slice<T>(s: &mut [T])
+where
+    T: Word,
+{
+    let range = s.as_mut_ptr_range();
+    // NOTE(unsafe) the slice is valid, aligned and exclusively borrowed
+    unsafe {
+        zero_bss(range.start, range.end);
+    }
+}
+
+/// Initializes a slice from another one, like [`init_data`].
+///
+/// This is a safe wrapper for host-side tests and simulators, which have slices rather than
+/// linker symbols.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Panics
+///
+/// Panics if `dst` and `src` have different lengths.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn init_slice_from<T>(dst: &mut [T], src: &[T])
+where
+    T: Word,
+{
+    assert_eq!(
+        dst.len(),
+        src.len(),
+        "`dst` and `src` have different lengths"
+    );
+
+    let range = dst.as_mut_ptr_range();
+    // NOTE(unsafe) the slices are valid and aligned, and can not overlap as `dst` is exclusively
+    // borrowed
+    unsafe {
+        init_data(range.start, range.end, src.as_ptr());
+    }
+}
+
+/// Zeroes the `.bss` section with a single `write_bytes` call.
+///
+/// [`zero_bss`] uses volatile writes so that the compiler can not turn its loop into a call to
+/// `memset`/`memclr`: on some targets those symbols do not exist, or are themselves written in
+/// Rust and may rely on statics that have not been initialized yet. On large regions that
+/// safety margin is expensive, though. This function instead hands the whole region to
+/// [`core::ptr::write_bytes`], which lowers to an optimized `memset`.
+///
+/// Only use it when the target provides a `memset` that can run before `.bss` and `.data` are
+/// initialized.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+/// [`core::ptr::write_bytes`]: https://doc.rust-lang.org/core/ptr/fn.write_bytes.html
+///
+/// # Arguments
+///
+/// Same as [`zero_bss`].
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_fast<T>(sbss: *mut T, ebss: *mut T) -> usize
+where
+    T: Word,
+{
+    let words = region_words(sbss, ebss);
+    ptr::write_bytes(sbss, 0, words);
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+
+    words
+}
+
+/// Zeroes the `.bss` section, skipping the words that already read as zero.
+///
+/// On parts whose RAM is emulated in flash, or is a persistent memory such as FRAM, every write
+/// wears the cells. This function reads each word first and only writes it if it is non-zero,
+/// trading a read for each avoided write. Both the reads and the writes are volatile.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// Same as [`zero_bss`].
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_sparse<T>(sbss: *mut T, ebss: *mut T)
+where
+    T: Word,
+{
+    zero_sparse(&mut Volatile, sbss, ebss)
+}
+
+unsafe fn zero_sparse<A, T>(mem: &mut A, mut sbss: *mut T, ebss: *mut T)
+where
+    A: Access,
+    T: Word,
+{
+    debug_assert!(is_aligned(sbss), "`sbss` is not aligned");
+    debug_assert!(is_aligned(ebss), "`ebss` is not aligned");
+    debug_assert!(ebss >= sbss, "`ebss` is below `sbss`");
+
+    while sbss < ebss {
+        if bytes_of(&mem.read(sbss)).iter().any(|&byte| byte != 0) {
+            mem.write(sbss, T::ZERO);
+        }
+        sbss = sbss.offset(1);
+    }
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Zeroes the `.bss` section, then hands it to a cache maintenance callback.
+///
+/// On cores with a data cache, such as the Cortex-M7, the zeroes written by [`zero_bss`] may
+/// still sit in the cache when a DMA engine, which bypasses it, first accesses the section.
+/// After zeroing, this function calls `clean` once with the start of the section and its length
+/// in words, so that the HAL can clean (or clean and invalidate) the corresponding cache lines.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `sbss`, `ebss`: Same as [`zero_bss`].
+/// - `clean`: Cache maintenance callback.
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_cache<T, F>(sbss: *mut T, ebss: *mut T, mut clean: F) -> usize
+where
+    T: Word,
+    F: FnMut(*mut T, usize),
+{
+    let words = zero_bss(sbss, ebss);
+    clean(sbss, words);
+
+    words
+}
+
+/// Zeroes the `.bss` section like [`zero_bss`], then issues a release fence.
+///
+/// On a multicore part where one core zeroes a `.bss` section shared with the others before
+/// releasing them, the compiler fence at the end of [`zero_bss`] is not enough: the other cores
+/// could still observe the old contents. The hardware fence emitted here makes the zeroes visible
+/// to any core that observes a later store (e.g. a "boot done" flag written with at least
+/// `Ordering::Relaxed`) and then issues an acquire fence or load.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// Same as [`zero_bss`].
+///
+/// # Safety
+///
+/// Same as [`zero_bss`]. In addition, no other core may access the section until it has been
+/// released by the store that follows this call.
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_release<T>(sbss: *mut T, ebss: *mut T) -> usize
+where
+    T: Word,
+{
+    let words = zero_bss(sbss, ebss);
+    fence(Ordering::Release);
+
+    words
+}
+
+/// Zeroes the `.bss` section like [`zero_bss`], returning the address range that was written.
+///
+/// This is for validating a linker map against reality: comparing the returned range with the
+/// expected symbol values catches symbols that are wrong or pointers that were truncated by a
+/// cast on their way to the startup code.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// Same as [`zero_bss`].
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+///
+/// # Returns
+///
+/// The addresses of the first and the last byte that were zeroed, i.e. the inclusive range
+/// `sbss ..= ebss - 1`, or `(0, 0)` if the section is empty.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_traced<T>(sbss: *mut T, ebss: *mut T) -> (usize, usize)
+where
+    T: Word,
+{
+    let words = zero_bss(sbss, ebss);
+    if words == 0 {
+        return (0, 0);
+    }
+
+    let first = sbss as usize;
+    (first, first + words * mem::size_of::<T>() - 1)
+}
+
+/// Resumable zeroing of the `.bss` section, a chunk at a time.
+///
+/// On a cooperatively scheduled boot that can not block for the whole of [`zero_bss`], this
+/// tracks how far the zeroing has progressed so that it can be interleaved with other tasks:
+///
+/// ```no_run
+/// # use r0::BssZeroProgress;
+/// # fn poll_other_tasks() {}
+/// # unsafe fn before_main(sbss: *mut u32, ebss: *mut u32) {
+/// let mut bss = BssZeroProgress::new(sbss, ebss);
+/// while !bss.step(256) {
+///     poll_other_tasks();
+/// }
+/// # }
+/// ```
+///
+/// [`zero_bss`]: fn.zero_bss.html
+#[derive(Debug)]
+pub struct BssZeroProgress<T> {
+    next: *mut T,
+    end: *mut T,
+}
+
+impl<T> BssZeroProgress<T>
+where
+    T: Word,
+{
+    /// Prepares the zeroing of the `sbss -> ebss` region; nothing is written yet.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`zero_bss`]. In addition, the region must remain valid for writes, and nothing may
+    /// access it, until [`step`] has returned `true`.
+    ///
+    /// [`step`]: #method.step
+    /// [`zero_bss`]: fn.zero_bss.html
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub unsafe fn new(sbss: *mut T, ebss: *mut T) -> Self {
+        debug_assert!(is_aligned(sbss), "`sbss` is not aligned");
+        debug_assert!(is_aligned(ebss), "`ebss` is not aligned");
+        debug_assert!(ebss >= sbss, "`ebss` is below `sbss`");
+
+        BssZeroProgress {
+            next: sbss,
+            end: ebss,
+        }
+    }
+
+    /// Zeroes up to `max_words` more words of the region.
+    ///
+    /// Returns `true` once the whole region has been zeroed.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn step(&mut self, max_words: usize) -> bool {
+        // NOTE(unsafe) `new` checked the boundaries of the region, and `next` never moves past
+        // `end`
+        unsafe {
+            let words = cmp::min(max_words, region_words(self.next, self.end));
+            let end = self.next.add(words);
+            zero_bss_with(self.next, end, |_| {});
+            self.next = end;
+        }
+
+        self.next == self.end
+    }
+}
+
+/// Zeroes the `.bss` section from its highest word down to its lowest one.
+///
+/// This zeroes the same words as [`zero_bss`], in the opposite order. When a heap that grows
+/// downward sits right above `.bss`, walking down keeps the caches and prefetchers primed for
+/// the access pattern that follows.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// Same as [`zero_bss`].
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_reverse<T>(sbss: *mut T, mut ebss: *mut T)
+where
+    T: Word,
+{
+    debug_assert!(is_aligned(sbss), "`sbss` is not aligned");
+    debug_assert!(is_aligned(ebss), "`ebss` is not aligned");
+    debug_assert!(ebss >= sbss, "`ebss` is below `sbss`");
+
+    while ebss > sbss {
+        ebss = ebss.offset(-1);
+        // NOTE(volatile) to prevent this from being transformed into `memclr`
+        ptr::write_volatile(ebss, T::ZERO);
+    }
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Zeroes the `.bss` section in bursts of `burst_words` words.
+///
+/// Some external memory controllers combine consecutive writes into bus bursts, and are much
+/// slower at isolated single-word writes. This function zeroes the region in chunks of
+/// `burst_words` back-to-back volatile stores with no other memory access in between; a final
+/// chunk shorter than `burst_words` covers the rest of the region. `burst_words` is the tuning
+/// knob: set it to the size of the controller's write-combining buffer, or of a cache line, in
+/// words of type `T`.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `sbss`, `ebss`: Same as [`zero_bss`].
+/// - `burst_words`: Number of words written per burst.
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+///
+/// # Panics
+///
+/// Panics if `burst_words` is zero.
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_burst<T>(sbss: *mut T, ebss: *mut T, burst_words: usize) -> usize
+where
+    T: Word,
+{
+    assert!(burst_words != 0, "`burst_words` is zero");
+
+    let words = region_words(sbss, ebss);
+    let mut done = 0;
+    while done < words {
+        let burst = cmp::min(burst_words, words - done);
+        let start = sbss.add(done);
+        for i in 0..burst {
+            ptr::write_volatile(start.add(i), T::ZERO);
+        }
+        done += burst;
+    }
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+
+    words
+}
+
+/// Number of words [`zero_bss_with`] zeroes between two invocations of its progress callback.
+///
+/// [`zero_bss_with`]: fn.zero_bss_with.html
+pub const PROGRESS_INTERVAL: usize = 1024;
+
+/// Zeroes the `.bss` section, reporting progress along the way.
+///
+/// This is [`zero_bss`] for regions large enough (e.g. external SDRAM) that zeroing them takes
+/// noticeable time. After every [`PROGRESS_INTERVAL`] words, `f` is called with the number of
+/// words zeroed so far, which gives the caller a chance to kick a watchdog or blink an LED.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+/// [`PROGRESS_INTERVAL`]: constant.PROGRESS_INTERVAL.html
+///
+/// # Arguments
+///
+/// - `sbss`: Pointer to the start of the `.bss` section in RAM.
+/// - `ebss`: Pointer to the open/non-inclusive end of the `.bss` section in
+///   RAM (the value behind this pointer will not be modified).
+/// - `f`: Progress callback. It must not access the `.bss` section.
+/// - Use `T` to indicate the alignment of the `.bss` section.
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_with<T, F>(sbss: *mut T, ebss: *mut T, mut f: F) -> usize
+where
+    T: Word,
+    F: FnMut(usize),
+{
+    debug_assert!(is_aligned(sbss), "`sbss` is not aligned");
+    debug_assert!(is_aligned(ebss), "`ebss` is not aligned");
+    debug_assert!(ebss >= sbss, "`ebss` is below `sbss`");
+
+    let words = region_words(sbss, ebss);
+    let mut done = 0;
+    while done < words {
+        let chunk = cmp::min(PROGRESS_INTERVAL, words - done);
+        let (start, end) = (sbss.add(done), sbss.add(done + chunk));
+
+        // NOTE(volatile) to prevent this from being transformed into `memclr`, unless the user
+        // has told us that the target provides one
+        #[cfg(not(feature = "allow-memset"))]
+        set_region::<T, true>(start, end, T::ZERO);
+        #[cfg(feature = "allow-memset")]
+        set_region::<T, false>(start, end, T::ZERO);
+        done += chunk;
+
+        if chunk == PROGRESS_INTERVAL {
+            f(done);
+        }
+    }
+
+    // Ensure that any accesses of `static`s are not reordered before the `.bss` section is
+    // initialized.
+    // We use `SeqCst`, because `Acquire` only prevents later accesses from being reordered before
+    // *reads*, but this method only *writes* to the locations.
+    compiler_fence(Ordering::SeqCst);
+
+    words
+}
+
+/// Zeroes the `.bss` section, calling `kick` every `interval` words.
+///
+/// This is [`zero_bss_with`] with a caller-chosen interval, for boards whose watchdog is already
+/// running at reset and would expire while a large region is zeroed. `kick` is called after
+/// every `interval` words, i.e. `words / interval` times in total; it would usually feed the
+/// watchdog, which this crate knows nothing about.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+/// [`zero_bss_with`]: fn.zero_bss_with.html
+///
+/// # Arguments
+///
+/// - `sbss`, `ebss`: Same as [`zero_bss`].
+/// - `interval`: Number of words zeroed between two calls of `kick`.
+/// - `kick`: Callback. It must not access the `.bss` section.
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+///
+/// # Panics
+///
+/// Panics if `interval` is zero.
+///
+/// # Returns
+///
+/// The number of words (of type `T`) that were zeroed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_kick<T, F>(sbss: *mut T, ebss: *mut T, interval: usize, mut kick: F) -> usize
+where
+    T: Word,
+    F: FnMut(),
+{
+    assert!(interval != 0, "`interval` is zero");
+
+    let words = region_words(sbss, ebss);
+    let mut done = 0;
+    while words - done >= interval {
+        zero_bss_with(sbss.add(done), sbss.add(done + interval), |_| {});
+        done += interval;
+        kick();
+    }
+    zero_bss_with(sbss.add(done), ebss, |_| {});
+
+    words
+}
+
+/// Fills a memory region with `value`.
+///
+/// This is the generalization of [`zero_bss`] to an arbitrary word value. It can be used, for
+/// example, to stamp a `.noinit` region with a sentinel pattern (e.g. `0xDEADBEEF`) so that
+/// reads of uninitialized memory are easy to spot while debugging.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region (the value behind this pointer
+///   will not be modified).
+/// - `value`: The value written to every word of the region.
+/// - Use `T` to indicate the alignment of the region.
+///
+/// # Safety
+///
+/// - `end >= start`.
+/// - `start` and `end` must be `T` aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn fill<T>(start: *mut T, end: *mut T, value: T)
+where
+    T: Word,
+{
+    set_region::<T, true>(start, end, value);
+}
+
+/// Fills a memory region with `value`, with the volatility of the writes picked by `VOLATILE`.
+///
+/// With `VOLATILE = true` every word is written with `ptr::write_volatile`, as in [`zero_bss`]
+/// and [`fill`]; this prevents the compiler from transforming the loop into a call to `memset`,
+/// which may not be usable yet. With `VOLATILE = false` plain writes are used, which the compiler
+/// is free to merge, unroll or replace with `memset` for a faster fill.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+/// [`fill`]: fn.fill.html
+///
+/// # Arguments
+///
+/// Same as [`fill`].
+///
+/// # Safety
+///
+/// Same as [`fill`]. In addition, with `VOLATILE = false`, `memset` must be callable if the
+/// compiler chooses to emit a call to it.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn set_region<T, const VOLATILE: bool>(start: *mut T, end: *mut T, value: T)
+where
+    T: Word,
+{
+    // NOTE(miri) write the region through a slice with ordinary writes, so that Miri checks the
+    // accesses against a single borrow of the whole region
+    #[cfg(miri)]
+    for word in slice::from_raw_parts_mut(start, region_words(start, end)) {
+        *word = value;
+    }
+
+    #[cfg(not(miri))]
+    {
+        let mut start = start;
+        while start < end {
+            if VOLATILE {
+                ptr::write_volatile(start, value);
+            } else {
+                ptr::write(start, value);
+            }
+            start = start.offset(1);
+        }
+    }
+
+    // Ensure that any accesses of the region are not reordered before it is initialized.
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Fills a memory region with `byte`, using a single `write_bytes` call.
+///
+/// Unlike [`fill`], which writes a word value with volatile writes, this stamps every byte of the
+/// region with the same value, e.g. `0xFF` to emulate erased flash in RAM, and lets the compiler
+/// lower the fill to a `memset` call. The target must therefore provide a `memset` symbol; use
+/// [`fill_bytes_volatile`] on regions where that is not acceptable, such as memory-mapped ones.
+///
+/// [`fill`]: fn.fill.html
+/// [`fill_bytes_volatile`]: fn.fill_bytes_volatile.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region (the value behind this pointer
+///   will not be modified).
+/// - `byte`: The value written to every byte of the region.
+///
+/// # Safety
+///
+/// - `end >= start`.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn fill_bytes(start: *mut u8, end: *mut u8, byte: u8) {
+    ptr::write_bytes(start, byte, region_words(start, end));
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Fills a memory region with `byte`, using volatile byte writes.
+///
+/// This is [`fill_bytes`] for regions where every write must reach the memory, one byte at a
+/// time, such as memory-mapped peripherals or RAM behind a bus that does not support wider
+/// accesses.
+///
+/// [`fill_bytes`]: fn.fill_bytes.html
+///
+/// # Arguments
+///
+/// Same as [`fill_bytes`].
+///
+/// # Safety
+///
+/// Same as [`fill_bytes`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn fill_bytes_volatile(start: *mut u8, end: *mut u8, byte: u8) {
+    fill(start, end, byte);
+}
+
+/// Fills a memory region with the index of each word, for debugging reads of uninitialized
+/// memory.
+///
+/// Unlike a constant poison pattern, such as [`PREFILL_PATTERN`], a value captured from a read of
+/// memory poisoned this way tells which word was read: word `n` of the region holds `n`. Use it
+/// in debug builds before running code suspected of reading `.bss` early, and zero the region
+/// with [`zero_bss`] afterwards as usual.
+///
+/// [`PREFILL_PATTERN`]: constant.PREFILL_PATTERN.html
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region (the value behind this pointer
+///   will not be modified).
+///
+/// # Safety
+///
+/// - `end >= start`.
+/// - `start` and `end` must be 4-byte aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn poison_indexed(start: *mut u32, end: *mut u32) {
+    init_with(start, end, |i| i as u32);
+}
+
+/// Initializes every word of a memory region with a value computed from its index.
+///
+/// Word `i` of the region (counting from `start`) is set to `f(i)`. This generalizes [`fill`]
+/// and [`zero_bss`], and can be used to build simple tables, such as an incrementing ramp, at
+/// boot without keeping a copy of them in flash.
+///
+/// [`fill`]: fn.fill.html
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region (the value behind this pointer
+///   will not be modified).
+/// - `f`: Computes the value of a word from its index. It must not access the region.
+/// - Use `T` to indicate the alignment of the region.
+///
+/// # Safety
+///
+/// - `end >= start`.
+/// - `start` and `end` must be `T` aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_with<T, F>(mut start: *mut T, end: *mut T, mut f: F)
+where
+    T: Word,
+    F: FnMut(usize) -> T,
+{
+    let mut index = 0;
+    while start < end {
+        // NOTE(volatile) to prevent this from being transformed into `memset`
+        ptr::write_volatile(start, f(index));
+        start = start.offset(1);
+        index += 1;
+    }
+
+    // Ensure that any accesses of the region are not reordered before it is initialized.
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Fills a memory region with a sequence of repeated patterns.
+///
+/// This generalizes [`fill`] to regions that are not a single repeated value, such as a
+/// pre-seeded ring buffer, from a compact table kept in flash. For each `(pattern, count)` entry
+/// of `fills`, `count` copies of `pattern` are written, each entry continuing where the previous
+/// one stopped.
+///
+/// [`fill`]: fn.fill.html
+///
+/// # Arguments
+///
+/// - `dst`: Pointer to the start of the region.
+/// - `fills`: The `(pattern, count)` entries, in order.
+///
+/// # Safety
+///
+/// - `dst` must be 4-byte aligned, and valid for writes of as many words as the `count`s of
+///   `fills` add up to.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_fill_table(mut dst: *mut u32, fills: &[(u32, usize)]) {
+    for &(pattern, count) in fills {
+        let end = dst.add(count);
+        fill(dst, end, pattern);
+        dst = end;
+    }
+}
+
+/// Writes a few 32-bit values at given offsets into a region, e.g. right after [`zero_bss`].
+///
+/// This covers sections that are mostly zero with a handful of non-zero defaults, described by a
+/// compact table of `(offset, value)` pokes kept in flash.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `base`: Pointer to the start of the region.
+/// - `pokes`: The `(offset, value)` pairs; `offset` is in bytes, from `base`.
+///
+/// # Safety
+///
+/// - For every poke, `base + offset` must be 4-byte aligned and valid for a 32-bit write.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn apply_pokes(base: *mut u8, pokes: &[(usize, u32)]) {
+    for &(offset, value) in pokes {
+        debug_assert!(
+            offset
+                .checked_add(4)
+                .and_then(|end| (base as usize).checked_add(end))
+                .is_some(),
+            "poke at offset {} is out of the address space",
+            offset
+        );
+
+        let dst = base.add(offset) as *mut u32;
+        debug_assert!(is_aligned(dst), "poke at offset {} is not aligned", offset);
+
+        ptr::write_volatile(dst, value);
+    }
+
+    // Ensure that any accesses of the region are not reordered before it is initialized.
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Writes every word of a memory region once, to initialize its ECC syndrome bits.
+///
+/// On RAM protected by ECC, reading a word that has never been written faults, as its check bits
+/// are still random. [`zero_bss`] takes care of `.bss`, but regions such as `.noinit` or the heap
+/// are not touched during startup; call this function on them before anything reads them. The
+/// words are written with zero, using volatile writes, so that the compiler can not elide them
+/// regardless of the `allow-memset` feature.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region (the value behind this pointer
+///   will not be modified).
+/// - Use `T` to indicate the width of the ECC granule, e.g. `u64` for 64-bit ECC words.
+///
+/// # Safety
+///
+/// - `end >= start`.
+/// - `start` and `end` must be `T` aligned.
+/// - Nothing may access the region while it is being primed.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn ecc_prime<T>(start: *mut T, end: *mut T)
+where
+    T: Word,
+{
+    fill(start, end, T::ZERO);
+}
+
+/// Prepares the heap region for an allocator, returning its start address and size in bytes.
+///
+/// The returned pair is what allocators such as `linked_list_allocator` expect in their `init`
+/// method. The heap is not touched during startup, so on RAM protected by ECC the allocator (or
+/// the application, through memory it has allocated) could read words that have never been
+/// written and fault; with `prime` set, every word of the region is zeroed first, as in
+/// [`ecc_prime`]. This also hands the allocator zeroed memory.
+///
+/// [`ecc_prime`]: fn.ecc_prime.html
+///
+/// # Arguments
+///
+/// - `sheap`: Pointer to the start of the heap region in RAM.
+/// - `eheap`: Pointer to the open/non-inclusive end of the heap region in RAM (the value behind
+///   this pointer will not be modified).
+/// - `prime`: Whether to zero the region before returning it.
+///
+/// # Safety
+///
+/// - `eheap >= sheap`.
+/// - If `prime` is set, `sheap` and `eheap` must be 4-byte aligned, and nothing may access the
+///   region while it is being primed. Use [`ecc_prime`] directly for wider ECC granules.
+///
+/// # Returns
+///
+/// The address of the start of the heap, and its size in bytes.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn heap_init(sheap: *mut u8, eheap: *mut u8, prime: bool) -> (usize, usize) {
+    debug_assert!(eheap >= sheap, "`eheap` is below `sheap`");
+
+    if prime {
+        debug_assert!(
+            (sheap as usize | eheap as usize) & 3 == 0,
+            "the heap is not 4-byte aligned"
+        );
+        ecc_prime(sheap as *mut u32, eheap as *mut u32);
+    }
+
+    (sheap as usize, region_words(sheap, eheap))
+}
+
+/// An entry of a copy table, as walked by [`init_copy_table`].
+///
+/// [`init_copy_table`]: fn.init_copy_table.html
+///
+/// This matches the layout of the `__copy_table` emitted by ARM toolchains and the CMSIS
+/// startup code: each entry is a (load address, VMA, length) triple of machine words.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CopyTableEntry {
+    /// Load Memory Address (LMA) of the section. Data will be copied from here.
+    pub src: usize,
+    /// Virtual Memory Address (VMA) of the section in RAM.
+    pub dst: usize,
+    /// Length of the section, in 32-bit words.
+    pub words: usize,
+}
+
+/// An entry of a zero table, as walked by [`zero_table`].
+///
+/// [`zero_table`]: fn.zero_table.html
+///
+/// This matches the layout of the `__zero_table` emitted by ARM toolchains and the CMSIS
+/// startup code: each entry is a (VMA, length) pair of machine words.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct ZeroTableEntry {
+    /// Virtual Memory Address (VMA) of the section in RAM.
+    pub dst: usize,
+    /// Length of the section, in 32-bit words.
+    pub words: usize,
+}
+
+/// Initializes every section described by a copy table.
+///
+/// The table is an array of [`CopyTableEntry`] placed in flash by the linker script. For
+/// example:
+///
+/// ```text
+/// .copy.table : ALIGN(4)
+/// {
+///     __copy_table_start__ = .;
+///     LONG(LOADADDR(.data));
+///     LONG(ADDR(.data));
+///     LONG(SIZEOF(.data) / 4);
+///     __copy_table_end__ = .;
+/// } > FLASH
+/// ```
+///
+/// [`CopyTableEntry`]: struct.CopyTableEntry.html
+///
+/// # Arguments
+///
+/// - `table_start`: Pointer to the first entry of the table.
+/// - `table_end`: Pointer to the open/non-inclusive end of the table.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `table_end >= table_start`, and the table must consist of whole [`CopyTableEntry`]s.
+/// - Every entry must satisfy the safety requirements of [`init_data`] for `T = u32`.
+///
+/// [`init_data`]: fn.init_data.html
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_copy_table(table_start: *const usize, table_end: *const usize) {
+    let mut entry = table_start as *const CopyTableEntry;
+    let end = table_end as *const CopyTableEntry;
+
+    while entry < end {
+        let CopyTableEntry { src, dst, words } = ptr::read(entry);
+        let dst = dst as *mut u32;
+        init_data(dst, dst.add(words), src as *const u32);
+        entry = entry.offset(1);
+    }
+}
+
+/// Zeroes every section described by a zero table.
+///
+/// The table is an array of [`ZeroTableEntry`] placed in flash by the linker script. For
+/// example:
+///
+/// ```text
+/// .zero.table : ALIGN(4)
+/// {
+///     __zero_table_start__ = .;
+///     LONG(ADDR(.bss));
+///     LONG(SIZEOF(.bss) / 4);
+///     __zero_table_end__ = .;
+/// } > FLASH
+/// ```
+///
+/// [`ZeroTableEntry`]: struct.ZeroTableEntry.html
+///
+/// # Arguments
+///
+/// - `table_start`: Pointer to the first entry of the table.
+/// - `table_end`: Pointer to the open/non-inclusive end of the table.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `table_end >= table_start`, and the table must consist of whole [`ZeroTableEntry`]s.
+/// - Every entry must satisfy the safety requirements of [`zero_bss`] for `T = u32`.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_table(table_start: *const usize, table_end: *const usize) {
+    let mut entry = table_start as *const ZeroTableEntry;
+    let end = table_end as *const ZeroTableEntry;
+
+    while entry < end {
+        let ZeroTableEntry { dst, words } = ptr::read(entry);
+        let dst = dst as *mut u32;
+        zero_bss(dst, dst.add(words));
+        entry = entry.offset(1);
+    }
+}
+
+/// A record of a relocation table, as walked by [`apply_reloc_table`].
+///
+/// [`apply_reloc_table`]: fn.apply_reloc_table.html
+///
+/// Unlike [`CopyTableEntry`], this layout is not tied to a particular toolchain: each record is a
+/// (VMA, load address, length in bytes) triple of 32-bit words, as emitted by a custom build tool.
+/// The layout is the same whatever the pointer width of the target.
+///
+/// [`CopyTableEntry`]: struct.CopyTableEntry.html
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct RelocRecord {
+    /// Virtual Memory Address (VMA) of the region in RAM.
+    pub dst: u32,
+    /// Load Memory Address (LMA) of the region. Data will be copied from here.
+    pub src: u32,
+    /// Length of the region, in bytes.
+    pub len_bytes: u32,
+}
+
+/// Copies every region described by a relocation table.
+///
+/// [`RelocRecord`]: struct.RelocRecord.html
+///
+/// # Arguments
+///
+/// - `records`: Pointer to the first [`RelocRecord`] of the table.
+/// - `count`: Number of records in the table.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `records` must be valid for reads of `count` [`RelocRecord`]s.
+/// - For every record, `src` must be valid for reads and `dst` valid for writes of `len_bytes`
+///   bytes, and the two regions must not overlap. They have no alignment requirement.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn apply_reloc_table(records: *const RelocRecord, count: usize) {
+    for i in 0..count {
+        let RelocRecord {
+            dst,
+            src,
+            len_bytes,
+        } = ptr::read(records.add(i));
+        copy_region(
+            dst as usize as *mut u8,
+            src as usize as *const u8,
+            len_bytes as usize,
+        );
+    }
+}
+
+/// Applies a sparse image, e.g. a SREC or Intel HEX file that a bootloader has staged in RAM.
+///
+/// Each chunk is a destination address and the bytes to copy there. The chunks are applied in
+/// order, so where the destinations of two chunks overlap the last writer wins.
+///
+/// # Arguments
+///
+/// - `chunks`: The (destination, data) pairs to apply.
+///
+/// # Safety
+///
+/// - Must be called before the memory the chunks are applied to is used.
+/// - For every chunk, the destination must be valid for writes of `data.len()` bytes and must not
+///   overlap `data` itself. It has no alignment requirement.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn apply_chunks(chunks: &[(*mut u8, &[u8])]) {
+    for &(dst, data) in chunks {
+        copy_region(dst, data.as_ptr(), data.len());
+    }
+}
+
+/// A memory section, as walked by [`run_sections`].
+///
+/// [`run_sections`]: fn.run_sections.html
+///
+/// The constructors are `const fn`s, so that a table of descriptors can be a `static` living in
+/// flash. Each descriptor is a (start, end, load address) triple of machine words, plus a flag
+/// marking sections that are zeroed rather than copied, like `.bss`. The flag is separate from
+/// `lma`, as zero is a valid load address on many parts.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct SectionDesc {
+    /// Start of the section in RAM.
+    pub start: usize,
+    /// Open/non-inclusive end of the section in RAM.
+    pub end: usize,
+    /// Load Memory Address (LMA) of the section. Ignored if `zero` is set.
+    pub lma: usize,
+    /// Whether the section is to be zeroed instead of copied from `lma`.
+    pub zero: bool,
+}
+
+impl SectionDesc {
+    /// Creates the descriptor of a section copied from `lma`, like `.data`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub const fn new(start: usize, end: usize, lma: usize) -> Self {
+        SectionDesc {
+            start,
+            end,
+            lma,
+            zero: false,
+        }
+    }
+
+    /// Creates the descriptor of a section to be zeroed, like `.bss`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub const fn zeroed(start: usize, end: usize) -> Self {
+        SectionDesc {
+            start,
+            end,
+            lma: 0,
+            zero: true,
+        }
+    }
+}
+
+/// Initializes every section of a table of [`SectionDesc`]s, in order.
+///
+/// Sections created with [`SectionDesc::zeroed`] are zeroed like [`zero_bss`]; the others are
+/// initialized from their `lma` like [`init_data`]. Both are processed as 32-bit words.
+///
+/// [`SectionDesc`]: struct.SectionDesc.html
+/// [`SectionDesc::zeroed`]: struct.SectionDesc.html#method.zeroed
+/// [`init_data`]: fn.init_data.html
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - Every section must satisfy the safety requirements of [`init_data`] or [`zero_bss`] for
+///   `T = u32`.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn run_sections(sections: &[SectionDesc]) {
+    for section in sections {
+        let (start, end) = (section.start as *mut u32, section.end as *mut u32);
+        if section.zero {
+            zero_bss(start, end);
+        } else {
+            init_data(start, end, section.lma as *const u32);
+        }
+    }
+}
+
+/// How [`init_section`] initializes a section.
+///
+/// [`init_section`]: fn.init_section.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SectionKind {
+    /// Zero the section, like [`zero_bss`].
+    ///
+    /// [`zero_bss`]: fn.zero_bss.html
+    Zero,
+    /// Copy the section from its Load Memory Address (LMA), like [`init_data`].
+    ///
+    /// [`init_data`]: fn.init_data.html
+    Copy {
+        /// Load Memory Address (LMA) of the section.
+        lma: *const u8,
+    },
+    /// Fill the section with a word value, like [`fill`].
+    ///
+    /// [`fill`]: fn.fill.html
+    Fill {
+        /// The value written to every word of the section.
+        value: u32,
+    },
+}
+
+/// Initializes a section as described by `kind`.
+///
+/// This is a single dispatch point over [`zero_bss`], [`init_data`] and [`fill`], so that a whole
+/// memory map can be described as a table of `(start, end, kind)` entries and initialized in one
+/// loop. Every kind processes the section as 32-bit words.
+///
+/// [`fill`]: fn.fill.html
+/// [`init_data`]: fn.init_data.html
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the section in RAM.
+/// - `end`: Pointer to the open/non-inclusive end of the section in RAM.
+/// - `kind`: How to initialize the section.
+///
+/// # Safety
+///
+/// - The section must satisfy the safety requirements of the function `kind` dispatches to, for
+///   `T = u32`. In particular, `start`, `end` and the LMA of a [`SectionKind::Copy`] must be
+///   4-byte aligned.
+///
+/// [`SectionKind::Copy`]: enum.SectionKind.html#variant.Copy
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_section(start: *mut u8, end: *mut u8, kind: SectionKind) {
+    let (start, end) = (start as *mut u32, end as *mut u32);
+    match kind {
+        SectionKind::Zero => {
+            zero_bss(start, end);
+        }
+        SectionKind::Copy { lma } => {
+            init_data(start, end, lma as *const u32);
+        }
+        SectionKind::Fill { value } => fill(start, end, value),
+    }
+}
+
+/// Paints the stack region with `pattern` for stack usage measurements.
+///
+/// Together with [`stack_free_words`] this implements stack watermarking: paint the whole stack
+/// at boot, run the application for a while, then count how many words at the bottom of the
+/// stack still hold the pattern. A common choice of pattern is `0xC0DE_C0DE`.
+///
+/// [`stack_free_words`]: fn.stack_free_words.html
+///
+/// # Arguments
+///
+/// - `bottom`: Pointer to the lowest address of the stack region.
+/// - `top`: Pointer to the open/non-inclusive top of the stack region (the value behind this
+///   pointer will not be modified).
+/// - `pattern`: The value written to every word of the region.
+///
+/// # Safety
+///
+/// - `top >= bottom`.
+/// - `bottom` and `top` must be 4-byte aligned.
+/// - The `bottom -> top` region must not be in use, so this must be called before switching to
+///   the stack, or with the stack pointer above `top`.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn paint_stack(bottom: *mut u32, top: *mut u32, pattern: u32) {
+    fill(bottom, top, pattern);
+}
+
+/// Counts how many words at the bottom of a stack painted by [`paint_stack`] are untouched.
+///
+/// The stack grows downwards, so the scan starts at `bottom` and stops at the first word that no
+/// longer holds `pattern`. The returned count therefore is the stack headroom that has never
+/// been used.
+///
+/// [`paint_stack`]: fn.paint_stack.html
+///
+/// # Arguments
+///
+/// - `bottom`: Pointer to the lowest address of the stack region.
+/// - `top`: Pointer to the open/non-inclusive top of the stack region.
+/// - `pattern`: The value the region was painted with.
+///
+/// # Safety
+///
+/// - `top >= bottom`.
+/// - `bottom` and `top` must be 4-byte aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn stack_free_words(mut bottom: *const u32, top: *const u32, pattern: u32) -> usize {
+    let mut words = 0;
+    while bottom < top && ptr::read_volatile(bottom) == pattern {
+        bottom = bottom.offset(1);
+        words += 1;
+    }
+
+    words
+}
+
+/// Installs a stack canary at the low end of the stack.
+///
+/// Unlike [`paint_stack`], which measures stack usage by scanning the whole stack, a canary only
+/// detects whether the stack has overflowed, but [`check_stack_guard`] does so in constant time
+/// and can be called from an interrupt handler.
+///
+/// [`paint_stack`]: fn.paint_stack.html
+/// [`check_stack_guard`]: fn.check_stack_guard.html
+///
+/// # Arguments
+///
+/// - `guard`: Pointer to the lowest word of the stack region.
+/// - `magic`: The canary value.
+///
+/// # Safety
+///
+/// - `guard` must be 4-byte aligned and valid for writes.
+/// - `guard` must not be in use by the stack, or anything else, when this is called.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn install_stack_guard(guard: *mut u32, magic: u32) {
+    ptr::write_volatile(guard, magic);
+}
+
+/// Checks whether the stack canary installed by [`install_stack_guard`] is intact.
+///
+/// Returns `false` if the canary has been overwritten, which means that the stack has grown past
+/// its lower limit at some point.
+///
+/// [`install_stack_guard`]: fn.install_stack_guard.html
+///
+/// # Safety
+///
+/// - `guard` must be 4-byte aligned and valid for reads.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn check_stack_guard(guard: *const u32, magic: u32) -> bool {
+    ptr::read_volatile(guard) == magic
+}
+
+/// Initializes the `.data` section by expanding a run-length encoded image.
+///
+/// This lets the `.data` LMA hold a compressed image, trading some startup time for flash space.
+///
+/// # Stream format
+///
+/// The stream is a sequence of packets, each starting with a control byte `c`:
+///
+/// - If the most significant bit of `c` is set, the packet is a *repeat run*: it consists of one
+///   more byte `b`, which is written `(c & 0x7F) + 1` times (1 to 128 times).
+/// - Otherwise the packet is a *literal run*: the next `c + 1` bytes (1 to 128 bytes) are copied
+///   verbatim.
+///
+/// There is no terminator. Decoding stops as soon as the `.data` section is full; a run that
+/// extends past `edata` is truncated.
+///
+/// For example, the bytes `[7, 7, 7, 7, 1, 2]` are encoded as `[0x83, 7, 0x01, 1, 2]`.
+///
+/// # Arguments
+///
+/// - `sdata`: Pointer to the start of the `.data` section in RAM.
+/// - `edata`: Pointer to the open/non-inclusive end of the `.data` section in RAM (the value
+///   behind this pointer will not be modified).
+/// - `compressed`: Pointer to the run-length encoded image.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `edata >= sdata`.
+/// - `compressed` must point to a well-formed stream that expands to at least
+///   `edata - sdata` bytes.
+/// - The `sdata -> edata` region must not overlap with the stream.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_rle(mut sdata: *mut u8, edata: *mut u8, mut compressed: *const u8) {
+    while sdata < edata {
+        let control = ptr::read(compressed);
+        compressed = compressed.offset(1);
+
+        let run = usize::from(control & 0x7F) + 1;
+        let run = cmp::min(run, region_words(sdata, edata));
+        if control & 0x80 != 0 {
+            let byte = ptr::read(compressed);
+            compressed = compressed.offset(1);
+            for _ in 0..run {
+                ptr::write(sdata, byte);
+                sdata = sdata.offset(1);
+            }
+        } else {
+            for _ in 0..run {
+                ptr::write(sdata, ptr::read(compressed));
+                sdata = sdata.offset(1);
+                compressed = compressed.offset(1);
+            }
+        }
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Initializes the `.data` section by decompressing an LZ4 block.
+///
+/// The block is decoded straight into the `.data` section, without any scratch buffer or
+/// allocation, which lets large but compressible initialized data take less room in flash.
+///
+/// # Block format
+///
+/// `compressed` must point to a raw LZ4 block, as produced by `LZ4_compress_default` (i.e. the
+/// [LZ4 block format], without the frame header, checksums or size prefix of the LZ4 frame
+/// format). A block is a sequence of *sequences*, each made of:
+///
+/// 1. A token byte. Its high nibble is the number of literals; its low nibble is the match
+///    length minus 4.
+/// 2. If the literal count nibble is 15, extra length bytes follow, each of which is added to
+///    the count; the last one is smaller than 255.
+/// 3. The literal bytes, copied verbatim.
+/// 4. The match offset, as a little-endian `u16`: the match is copied from `offset` bytes before
+///    the current output position, and may overlap the bytes being written.
+/// 5. If the match length nibble is 15, extra length bytes follow, as for the literal count.
+///
+/// The last sequence of a block only contains literals. Decoding stops as soon as `dst_len`
+/// bytes have been written.
+///
+/// [LZ4 block format]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Block_format.md
+///
+/// # Arguments
+///
+/// - `dst`: Pointer to the start of the `.data` section in RAM.
+/// - `dst_len`: Length of the `.data` section, in bytes.
+/// - `compressed`: Pointer to the LZ4 block.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `compressed` must point to a well-formed LZ4 block that decompresses to at least `dst_len`
+///   bytes, and whose match offsets never point before `dst`.
+/// - The `dst -> dst + dst_len` region must not overlap with the block.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_lz4(dst: *mut u8, dst_len: usize, mut compressed: *const u8) {
+    unsafe fn read_len(src: &mut *const u8, nibble: u8) -> usize {
+        let mut len = usize::from(nibble);
+        if nibble == 15 {
+            loop {
+                let byte = ptr::read(*src);
+                *src = src.offset(1);
+                len += usize::from(byte);
+                if byte != 255 {
+                    break;
+                }
+            }
+        }
+        len
+    }
+
+    let mut written = 0;
+    while written < dst_len {
+        let token = ptr::read(compressed);
+        compressed = compressed.offset(1);
+
+        let literals = cmp::min(read_len(&mut compressed, token >> 4), dst_len - written);
+        copy_bytes(dst.add(written), compressed, literals);
+        compressed = compressed.add(literals);
+        written += literals;
+
+        if written == dst_len {
+            break;
+        }
+
+        let offset =
+            usize::from(ptr::read(compressed)) | usize::from(ptr::read(compressed.offset(1))) << 8;
+        compressed = compressed.offset(2);
+
+        let len = cmp::min(
+            read_len(&mut compressed, token & 0x0F) + 4,
+            dst_len - written,
+        );
+        // NOTE the match may overlap the bytes being written, so it must be copied forwards, one
+        // byte at a time
+        copy_bytes(dst.add(written), dst.add(written - offset), len);
+        written += len;
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Updates the `.data` section in place by applying a delta against its current contents.
+///
+/// For over-the-air updates that only change a few bytes of `.data`, the new image can be stored
+/// as a delta against the image already resident in RAM, which is much smaller than a full image.
+///
+/// # Stream format
+///
+/// The stream is a sequence of opcodes, each one byte, some followed by a length `n` stored as a
+/// little-endian `u16`:
+///
+/// - `0x00` (*END*): the delta is complete.
+/// - `0x01 n` (*SKIP*): the next `n` bytes of `dst` are left unchanged.
+/// - `0x02 n literal[n]` (*COPY*): the next `n` bytes of `dst` are overwritten with the `n`
+///   literal bytes that follow.
+///
+/// Decoding stops at the first *END*, or as soon as the end of `dst` is reached; a *SKIP* or
+/// *COPY* that extends past the end of `dst` is truncated. Bytes after the position reached are
+/// left unchanged.
+///
+/// For example, changing `[1, 2, 3, 4, 5]` into `[1, 2, 9, 4, 5]` takes the delta
+/// `[0x01, 2, 0, 0x02, 1, 0, 9, 0x00]`.
+///
+/// # Arguments
+///
+/// - `dst`: Pointer to the start of the `.data` section in RAM.
+/// - `dst_len`: Length of the `.data` section, in bytes.
+/// - `delta`: Pointer to the delta stream.
+///
+/// # Safety
+///
+/// - Must be called before the application has started.
+/// - `dst` must be valid for reads and writes of `dst_len` bytes.
+/// - `delta` must point to a well-formed stream. Opcodes other than the ones above are undefined
+///   behavior.
+/// - The `dst -> dst + dst_len` region must not overlap with the stream.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn apply_data_delta(dst: *mut u8, dst_len: usize, mut delta: *const u8) {
+    let mut pos = 0;
+    while pos < dst_len {
+        let opcode = ptr::read(delta);
+        if opcode == 0x00 {
+            break;
+        }
+
+        let n =
+            usize::from(ptr::read(delta.offset(1))) | usize::from(ptr::read(delta.offset(2))) << 8;
+        delta = delta.offset(3);
+
+        let len = cmp::min(n, dst_len - pos);
+        if opcode == 0x02 {
+            copy_bytes(dst.add(pos), delta, len);
+            delta = delta.add(n);
+        } else {
+            debug_assert!(opcode == 0x01, "invalid delta opcode {:#04x}", opcode);
+        }
+        pos += len;
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Computes the IEEE CRC-32 of a memory region.
+///
+/// This is the CRC used by Ethernet, zlib and PNG (polynomial `0x04C11DB7`, reflected, with an
+/// initial value and final XOR of `0xFFFF_FFFF`), computed bitwise to keep code size low. Calling
+/// it on both the `.data` section in RAM and its LMA after [`init_data`] gives a cheap check that
+/// the copy succeeded.
+///
+/// The CRC covers the bytes of the region in memory order, so the result does not depend on `T`.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region.
+/// - Use `T` to indicate the alignment of the region.
+///
+/// # Safety
+///
+/// - `end >= start`.
+/// - `start` and `end` must be `T` aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn crc32_region<T>(start: *const T, end: *const T) -> u32
+where
+    T: Word,
+{
+    let mut byte = start as *const u8;
+    let end = end as *const u8;
+
+    let mut crc = !0u32;
+    while byte < end {
+        crc ^= u32::from(ptr::read(byte));
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+        byte = byte.offset(1);
+    }
+
+    !crc
+}
+
+/// Zeroes the `.bss` section after checking that every bit of it can hold both a 0 and a 1.
+///
+/// The region is first filled with `0x5555_5555` and read back, then filled with `0xAAAA_AAAA`
+/// and read back, and finally zeroed. This catches stuck bits in the RAM holding `.bss` at a
+/// fraction of the cost of a full [`march_c_test`].
+///
+/// [`march_c_test`]: fn.march_c_test.html
+///
+/// # Arguments
+///
+/// - `sbss`: Pointer to the start of the `.bss` section in RAM.
+/// - `ebss`: Pointer to the open/non-inclusive end of the `.bss` section in
+///   RAM (the value behind this pointer will not be modified).
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `ebss >= sbss`.
+/// - `sbss` and `ebss` must be 4-byte aligned.
+///
+/// # Errors
+///
+/// Returns the address of the first word that failed to read back a pattern. The region is not
+/// zeroed in that case.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_scrubbed(sbss: *mut u32, ebss: *mut u32) -> Result<(), *mut u32> {
+    scrub(&mut Volatile, sbss, ebss)
+}
+
+unsafe fn scrub<A>(mem: &mut A, sbss: *mut u32, ebss: *mut u32) -> Result<(), *mut u32>
+where
+    A: Access,
+{
+    for &pattern in &[0x5555_5555, 0xAAAA_AAAA] {
+        march_element(mem, sbss, ebss, false, None, Some(pattern))?;
+        march_element(mem, sbss, ebss, false, Some(pattern), None)?;
+    }
+    march_element(mem, sbss, ebss, false, None, Some(0))?;
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+
+    Ok(())
+}
+
+/// Runs a destructive March C- test over a RAM region.
+///
+/// March C- detects stuck-at, transition and coupling faults by walking the region with the
+/// following sequence of march elements, where `0` is `0x0000_0000`, `1` is `0xFFFF_FFFF`, `⇑`
+/// means ascending and `⇓` descending address order:
+///
+/// `⇕(w0); ⇑(r0, w1); ⇑(r1, w0); ⇓(r0, w1); ⇓(r1, w0); ⇕(r0)`
+///
+/// The region is left zeroed when the test passes.
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region (the value behind this pointer
+///   will not be modified).
+///
+/// # Safety
+///
+/// - `end >= start`.
+/// - `start` and `end` must be 4-byte aligned.
+/// - The test clobbers the whole region, so it must not hold any live data. In particular, it
+///   must run before [`zero_bss`] and [`init_data`] when testing the RAM holding `.bss` and
+///   `.data`.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+/// [`init_data`]: fn.init_data.html
+///
+/// # Errors
+///
+/// Returns the address of the first word that failed to read back the expected value.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn march_c_test(start: *mut u32, end: *mut u32) -> Result<(), *mut u32> {
+    march_c(&mut Volatile, start, end)
+}
+
+unsafe fn march_c<A>(mem: &mut A, start: *mut u32, end: *mut u32) -> Result<(), *mut u32>
+where
+    A: Access,
+{
+    const ZERO: u32 = 0;
+    const ONE: u32 = !0;
+
+    march_element(mem, start, end, false, None, Some(ZERO))?;
+    march_element(mem, start, end, false, Some(ZERO), Some(ONE))?;
+    march_element(mem, start, end, false, Some(ONE), Some(ZERO))?;
+    march_element(mem, start, end, true, Some(ZERO), Some(ONE))?;
+    march_element(mem, start, end, true, Some(ONE), Some(ZERO))?;
+    march_element(mem, start, end, false, Some(ZERO), None)
+}
+
+/// Applies a single march element: for each word, in ascending or descending order, checks that
+/// it holds `expect` and then writes `write`.
+unsafe fn march_element<A>(
+    mem: &mut A,
+    start: *mut u32,
+    end: *mut u32,
+    descending: bool,
+    expect: Option<u32>,
+    write: Option<u32>,
+) -> Result<(), *mut u32>
+where
+    A: Access,
+{
+    let words = region_words(start, end);
+    for i in 0..words {
+        let word = if descending {
+            start.add(words - 1 - i)
+        } else {
+            start.add(i)
+        };
+
+        if let Some(expect) = expect {
+            if mem.read(word) != expect {
+                return Err(word);
+            }
+        }
+
+        if let Some(value) = write {
+            mem.write(word, value);
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies code meant to run from RAM (e.g. a `.ramfunc` or `.fast` section) out of flash.
+///
+/// The copy itself is the same as [`init_data`]; this function only exists so that startup code
+/// states its intent. Note, however, that writing instructions through the data bus is not
+/// enough to make them executable on every architecture. Cores with an instruction cache or a
+/// prefetch buffer (e.g. Cortex-M7, or any core where RAM is cacheable) must clean the data
+/// cache over the region, invalidate the instruction cache and issue an instruction
+/// synchronization barrier (`DSB` + `ISB` on ARM, `FENCE.I` on RISC-V) before jumping into the
+/// copied code. This crate is architecture agnostic and does not do that for you.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// - `sfunc`: Pointer to the start of the RAM code section.
+/// - `efunc`: Pointer to the open/non-inclusive end of the RAM code section (the value behind
+///   this pointer will not be modified).
+/// - `sifunc`: Load Memory Address (LMA) of the section. Code will be copied from here.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before any of the copied functions is called.
+/// - `efunc >= sfunc`.
+/// - The `sfunc -> efunc` region must not overlap with the `sifunc -> ...` region.
+/// - `sfunc`, `efunc` and `sifunc` must be 4-byte aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_ramfunc(sfunc: *mut u32, efunc: *mut u32, sifunc: *const u32) {
+    init_data(sfunc, efunc, sifunc);
+}
+
+/// Declares the standard section symbols and initializes the `.bss` and `.data` sections.
+///
+/// Invoked without arguments, this macro binds to the `_sbss`, `_ebss`, `_sdata`, `_edata` and
+/// `_sidata` symbols, treats the sections as 4-byte aligned, and calls [`zero_bss`] and
+/// [`init_data`] on them. The word type, and optionally the symbol names, can be overridden:
+///
+/// ```no_run
+/// unsafe fn before_main() {
+///     // Same as `r0::init!(u32, _sbss, _ebss, _sdata, _edata, _sidata)`
+///     r0::init!();
+/// }
+///
+/// unsafe fn before_main_native() {
+///     r0::init!(usize);
+/// }
+///
+/// unsafe fn before_main_custom() {
+///     r0::init!(u32, __bss_start, __bss_end, __data_start, __data_end, __data_load);
+/// }
+/// ```
+///
+/// The macro must be invoked in an `unsafe` context, and the [`init_data`] and [`zero_bss`]
+/// safety requirements apply to the symbols it binds to.
+///
+/// [`init_data`]: fn.init_data.html
+/// [`zero_bss`]: fn.zero_bss.html
+#[macro_export]
+macro_rules! init {
+    () => {
+        $crate::init!(u32)
+    };
+    ($word:ty) => {
+        $crate::init!($word, _sbss, _ebss, _sdata, _edata, _sidata)
+    };
+    ($word:ty, $sbss:ident, $ebss:ident, $sdata:ident, $edata:ident, $sidata:ident) => {{
+        extern "C" {
+            static mut $sbss: $word;
+            static mut $ebss: $word;
+
+            static mut $sdata: $word;
+            static mut $edata: $word;
+
+            static $sidata: $word;
+        }
+
+        $crate::zero_bss(
+            ::core::ptr::addr_of_mut!($sbss),
+            ::core::ptr::addr_of_mut!($ebss),
+        );
+        $crate::init_data(
+            ::core::ptr::addr_of_mut!($sdata),
+            ::core::ptr::addr_of_mut!($edata),
+            ::core::ptr::addr_of!($sidata),
+        );
+    }};
+}
+
+/// Initializes the `.bss` and `.data` sections laid out by the `cortex-m-rt` linker script.
+///
+/// This binds to the symbols that the `link.x` script of `cortex-m-rt` (0.6 and later) defines,
+/// all of them 4-byte aligned:
+///
+/// - `__sbss` and `__ebss`: start and open/non-inclusive end of `.bss`.
+/// - `__sdata` and `__edata`: start and open/non-inclusive end of `.data`.
+/// - `__sidata`: Load Memory Address (LMA) of `.data`.
+///
+/// The `Reset` handler of `cortex-m-rt` already initializes these sections before calling the
+/// entry point, so this is for custom reset handlers that keep using its linker script.
+///
+/// This function requires the `cortex-m-rt` feature and is only available on ARM targets.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - The program must be linked with the `cortex-m-rt` linker script, or one defining the same
+///   symbols with the same meaning.
+#[cfg(all(feature = "cortex-m-rt", target_arch = "arm"))]
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn run_init() {
+    extern "C" {
+        static mut __sbss: u32;
+        static mut __ebss: u32;
+
+        static mut __sdata: u32;
+        static mut __edata: u32;
+
+        static __sidata: u32;
+    }
+
+    zero_bss(ptr::addr_of_mut!(__sbss), ptr::addr_of_mut!(__ebss));
+    init_data(
+        ptr::addr_of_mut!(__sdata),
+        ptr::addr_of_mut!(__edata),
+        ptr::addr_of!(__sidata),
+    );
+}
+
+/// Initializes the `.data` section using the target's [`NativeWord`].
+///
+/// This is [`init_data`] with `T = NativeWord`, for portable startup code that should not
+/// hardcode the word width.
+///
+/// [`NativeWord`]: type.NativeWord.html
+/// [`init_data`]: fn.init_data.html
+///
+/// # Safety
+///
+/// Same as [`init_data`]. In particular, the section and its LMA must be aligned to the size of
+/// a pointer.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_native(
+    sdata: *mut NativeWord,
+    edata: *mut NativeWord,
+    sidata: *const NativeWord,
+) -> usize {
+    init_data(sdata, edata, sidata)
+}
+
+/// Zeroes the `.bss` section using the target's [`NativeWord`].
+///
+/// This is [`zero_bss`] with `T = NativeWord`, for portable startup code that should not
+/// hardcode the word width.
+///
+/// [`NativeWord`]: type.NativeWord.html
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Safety
+///
+/// Same as [`zero_bss`]. In particular, the section must be aligned to the size of a pointer.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_native(sbss: *mut NativeWord, ebss: *mut NativeWord) -> usize {
+    zero_bss(sbss, ebss)
+}
+
+/// Initializes the `.data` section using 64-bit words.
+///
+/// This is [`init_data`] with `T = u64`, the natural word width of 64-bit targets such as RV64 and
+/// AArch64, and the recommended way to initialize `.data` on them. It also exercises the 8-byte
+/// copy path of such a target when testing its startup code on a 64-bit host.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Safety
+///
+/// Same as [`init_data`]. In particular, the section and its LMA must be 8-byte aligned.
+#[cfg(feature = "wide-words")]
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data64(sdata: *mut u64, edata: *mut u64, sidata: *const u64) -> usize {
+    init_data(sdata, edata, sidata)
+}
+
+/// Zeroes the `.bss` section using 64-bit words.
+///
+/// This is [`zero_bss`] with `T = u64`, the natural word width of 64-bit targets such as RV64 and
+/// AArch64, and the recommended way to zero `.bss` on them.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Safety
+///
+/// Same as [`zero_bss`]. In particular, the section must be 8-byte aligned.
+#[cfg(feature = "wide-words")]
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss64(sbss: *mut u64, ebss: *mut u64) -> usize {
+    zero_bss(sbss, ebss)
+}
+
+/// Initializes the `.data` section using 16-bit words.
+///
+/// This is [`init_data`] with `T = u16`, the natural word width of 16-bit targets such as
+/// MSP430.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Safety
+///
+/// Same as [`init_data`]. In particular, the section and its LMA must be 2-byte aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data16(sdata: *mut u16, edata: *mut u16, sidata: *const u16) -> usize {
+    init_data(sdata, edata, sidata)
+}
+
+/// Zeroes the `.bss` section using 16-bit words.
+///
+/// This is [`zero_bss`] with `T = u16`, the natural word width of 16-bit targets such as MSP430.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Safety
+///
+/// Same as [`zero_bss`]. In particular, the section must be 2-byte aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss16(sbss: *mut u16, ebss: *mut u16) -> usize {
+    zero_bss(sbss, ebss)
+}
+
+/// Initializes the `.data` section using bytes.
+///
+/// This is [`init_data`] with `T = u8`, the natural word width of 8-bit targets such as AVR.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Safety
+///
+/// Same as [`init_data`]. The section and its LMA have no alignment requirement.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data8(sdata: *mut u8, edata: *mut u8, sidata: *const u8) -> usize {
+    init_data(sdata, edata, sidata)
+}
+
+/// Zeroes the `.bss` section using bytes.
+///
+/// This is [`zero_bss`] with `T = u8`, the natural word width of 8-bit targets such as AVR.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Safety
+///
+/// Same as [`zero_bss`]. The section has no alignment requirement.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss8(sbss: *mut u8, ebss: *mut u8) -> usize {
+    zero_bss(sbss, ebss)
+}
+
+/// A strategy for zeroing the `.bss` section, e.g. with a DMA engine.
+///
+/// This lets a HAL offload the zeroing of large regions to hardware while keeping the startup
+/// sequence in [`zero_bss_via`]. [`CpuZeroer`] is the default implementation.
+///
+/// [`zero_bss_via`]: fn.zero_bss_via.html
+/// [`CpuZeroer`]: struct.CpuZeroer.html
+///
+/// # Safety
+///
+/// When `zero` returns, every word of the `start -> end` region must read as zero. If the
+/// zeroing happens asynchronously, the implementation must wait for it to complete.
+pub unsafe trait BssZeroer {
+    /// Zeroes the `start -> end` region.
+    ///
+    /// # Safety
+    ///
+    /// - `end >= start`.
+    /// - `start` and `end` must be 4-byte aligned.
+    /// - The region must be valid for writes, and nothing may access it while it is being zeroed.
+    unsafe fn zero(&mut self, start: *mut u32, end: *mut u32);
+}
+
+/// A [`BssZeroer`] that zeroes memory from the CPU, using [`zero_bss`].
+///
+/// [`BssZeroer`]: trait.BssZeroer.html
+/// [`zero_bss`]: fn.zero_bss.html
+pub struct CpuZeroer;
+
+unsafe impl BssZeroer for CpuZeroer {
+    unsafe fn zero(&mut self, start: *mut u32, end: *mut u32) {
+        zero_bss(start, end);
+    }
+}
+
+/// Zeroes the `.bss` section using the given [`BssZeroer`].
+///
+/// [`BssZeroer`]: trait.BssZeroer.html
+///
+/// # Arguments
+///
+/// - `z`: The zeroing strategy.
+/// - `sbss`: Pointer to the start of the `.bss` section in RAM.
+/// - `ebss`: Pointer to the open/non-inclusive end of the `.bss` section in
+///   RAM (the value behind this pointer will not be modified).
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `ebss >= sbss`.
+/// - `sbss` and `ebss` must be 4-byte aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_via<Z>(z: &mut Z, sbss: *mut u32, ebss: *mut u32)
+where
+    Z: BssZeroer,
+{
+    z.zero(sbss, ebss);
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// A strategy for filling memory with a byte value, e.g. with a DMA engine reading from a fixed
+/// source.
+///
+/// Where [`BssZeroer`] only zeroes, this also covers fills with other byte values, so that a HAL
+/// implements the peripheral once and drives both [`zero_bss_with_filler`] and
+/// [`fill_with_filler`] through it. [`CpuFiller`] is the default implementation.
+///
+/// [`BssZeroer`]: trait.BssZeroer.html
+/// [`CpuFiller`]: struct.CpuFiller.html
+/// [`fill_with_filler`]: fn.fill_with_filler.html
+/// [`zero_bss_with_filler`]: fn.zero_bss_with_filler.html
+///
+/// # Safety
+///
+/// When `fill` returns, every byte of the `start -> start + len` region must read as `byte`. If
+/// the fill happens asynchronously, the implementation must wait for it to complete.
+pub unsafe trait MemFiller {
+    /// Fills the `len` bytes starting at `start` with `byte`.
+    ///
+    /// # Safety
+    ///
+    /// - The region must be valid for writes, and nothing may access it while it is being filled.
+    unsafe fn fill(&mut self, start: *mut u8, len: usize, byte: u8);
+}
+
+/// A [`MemFiller`] that fills memory from the CPU, using volatile byte writes.
+///
+/// [`MemFiller`]: trait.MemFiller.html
+pub struct CpuFiller;
+
+unsafe impl MemFiller for CpuFiller {
+    unsafe fn fill(&mut self, start: *mut u8, len: usize, byte: u8) {
+        fill_bytes_volatile(start, start.add(len), byte);
+    }
+}
+
+/// Zeroes the `.bss` section using the given [`MemFiller`].
+///
+/// [`MemFiller`]: trait.MemFiller.html
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `filler`: The fill strategy.
+/// - `sbss`, `ebss`: Same as [`zero_bss`].
+///
+/// # Safety
+///
+/// Same as [`zero_bss`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_with_filler<F, T>(filler: &mut F, sbss: *mut T, ebss: *mut T)
+where
+    F: MemFiller,
+    T: Word,
+{
+    let len = region_words(sbss, ebss) * mem::size_of::<T>();
+    filler.fill(sbss as *mut u8, len, 0);
+
+    // See `zero_bss_with`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Fills a memory region with `byte` using the given [`MemFiller`].
+///
+/// [`MemFiller`]: trait.MemFiller.html
+///
+/// # Arguments
+///
+/// - `filler`: The fill strategy.
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region (the value behind this pointer
+///   will not be modified).
+/// - `byte`: The value written to every byte of the region.
+///
+/// # Safety
+///
+/// - `end >= start`.
+/// - The region must be valid for writes, and nothing may access it while it is being filled.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn fill_with_filler<F>(filler: &mut F, start: *mut u8, end: *mut u8, byte: u8)
+where
+    F: MemFiller,
+{
+    filler.fill(start, region_words(start, end), byte);
+
+    // Ensure that any accesses of the region are not reordered before it is initialized.
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// A strategy for writing memory that only takes effect once committed, e.g. a RAM window backed
+/// by flash.
+///
+/// [`init_data_committed`] copies the whole `.data` section with [`write`] and then calls
+/// [`commit`] once, so that the commit step is batched. [`RamCommitter`] is the implementation
+/// for ordinary RAM.
+///
+/// [`init_data_committed`]: fn.init_data_committed.html
+/// [`write`]: trait.Committer.html#tymethod.write
+/// [`commit`]: trait.Committer.html#tymethod.commit
+/// [`RamCommitter`]: struct.RamCommitter.html
+///
+/// # Safety
+///
+/// When `commit` returns, every byte passed to `write` since the previous commit must read back
+/// from its destination. If the commit happens asynchronously, the implementation must wait for
+/// it to complete.
+pub unsafe trait Committer {
+    /// Writes the `len` bytes starting at `src` to `dst`, pending the next commit.
+    ///
+    /// # Safety
+    ///
+    /// - `src` must be valid for reads and `dst` for writes of `len` bytes, and the two regions
+    ///   must not overlap.
+    unsafe fn write(&mut self, dst: *mut u8, src: *const u8, len: usize);
+
+    /// Commits all the pending writes.
+    ///
+    /// # Safety
+    ///
+    /// - Nothing may access the written regions while they are being committed.
+    unsafe fn commit(&mut self);
+}
+
+/// A [`Committer`] for ordinary RAM, where writes take effect immediately and committing does
+/// nothing.
+///
+/// [`Committer`]: trait.Committer.html
+pub struct RamCommitter;
+
+unsafe impl Committer for RamCommitter {
+    unsafe fn write(&mut self, dst: *mut u8, src: *const u8, len: usize) {
+        copy_region(dst, src, len);
+    }
+
+    unsafe fn commit(&mut self) {}
+}
+
+/// Initializes the `.data` section through the given [`Committer`], committing once at the end.
+///
+/// [`Committer`]: trait.Committer.html
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// - `committer`: The write strategy.
+/// - `sdata`, `edata`, `sidata`: Same as [`init_data`].
+///
+/// # Safety
+///
+/// Same as [`init_data`]. The pointers have no alignment requirement.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_committed<C>(
+    committer: &mut C,
+    sdata: *mut u8,
+    edata: *mut u8,
+    sidata: *const u8,
+) where
+    C: Committer,
+{
+    committer.write(sdata, sidata, region_words(sdata, edata));
+    committer.commit();
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Forces a compile-time check that `N` is a power of two when `OK` is used.
+struct AssertPowerOfTwo<const N: usize>;
+
+impl<const N: usize> AssertPowerOfTwo<N> {
+    const OK: () = assert!(N.is_power_of_two(), "`ALIGN` must be a power of two");
+}
+
+/// Initializes the `.data` section using the widest word type allowed by `ALIGN`.
+///
+/// This lets generic code, such as a HAL, request the best copy width for a section from a single
+/// entry point: `ALIGN` of 8 or more copies `u64`s, 4 copies `u32`s, 2 copies `u16`s and 1 copies
+/// bytes. Without the `wide-words` feature, `u32`s are copied for an `ALIGN` of 4 or more.
+/// `ALIGN` must be a power of two, which is checked at compile time:
+///
+/// ```compile_fail
+/// let lma = [0u8; 3];
+/// let mut data = [0u8; 3];
+/// let sdata = data.as_mut_ptr();
+/// unsafe { r0::init_data_aligned::<3>(sdata, sdata.add(3), lma.as_ptr()) };
+/// ```
+///
+/// # Arguments
+///
+/// - `sdata`: Pointer to the start of the `.data` section in RAM.
+/// - `edata`: Pointer to the open/non-inclusive end of the `.data` section in
+///   RAM (the value behind this pointer will not be modified).
+/// - `sidata`: `.data` section Load Memory Address (LMA). Data will be copied
+///   from here.
+/// - `ALIGN`: The alignment, in bytes, of the `.data` section and its LMA.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `edata >= sdata`.
+/// - The `sdata -> edata` region must not overlap with the `sidata -> ...`
+///   region.
+/// - `sdata`, `edata` and `sidata` must be `ALIGN` aligned.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_aligned<const ALIGN: usize>(
+    sdata: *mut u8,
+    edata: *mut u8,
+    sidata: *const u8,
+) {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertPowerOfTwo::<ALIGN>::OK;
+
+    #[cfg(feature = "wide-words")]
+    {
+        if ALIGN >= 8 {
+            init_data(sdata as *mut u64, edata as *mut u64, sidata as *const u64);
+            return;
+        }
+    }
+
+    if ALIGN >= 4 {
+        init_data(sdata as *mut u32, edata as *mut u32, sidata as *const u32);
+    } else if ALIGN == 2 {
+        init_data(sdata as *mut u16, edata as *mut u16, sidata as *const u16);
+    } else {
+        init_data(sdata, edata, sidata);
+    }
+}
+
+/// Zeroes the `.bss` section using the widest word its boundaries are aligned to.
+///
+/// This is for code that only has byte pointers to the boundaries of the section. The alignment
+/// of `sbss` and `ebss`, and therefore of the length of the section, is inspected at runtime:
+/// `u64`s are used when both are 8-byte aligned, `u32`s when they are 4-byte aligned, `u16`s when
+/// they are 2-byte aligned, and bytes otherwise. This costs a few branches before the zeroing
+/// loop. Without the `wide-words` feature, `u32`s are used for 8-byte aligned sections.
+///
+/// # Arguments
+///
+/// - `sbss`: Pointer to the start of the `.bss` section in RAM.
+/// - `ebss`: Pointer to the open/non-inclusive end of the `.bss` section in
+///   RAM (the value behind this pointer will not be modified).
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `ebss >= sbss`.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn zero_bss_auto(sbss: *mut u8, ebss: *mut u8) {
+    let bits = sbss as usize | ebss as usize;
+
+    #[cfg(feature = "wide-words")]
+    {
+        if bits & 7 == 0 {
+            zero_bss(sbss as *mut u64, ebss as *mut u64);
+            return;
+        }
+    }
+
+    if bits & 3 == 0 {
+        zero_bss(sbss as *mut u32, ebss as *mut u32);
+    } else if bits & 1 == 0 {
+        zero_bss(sbss as *mut u16, ebss as *mut u16);
+    } else {
+        zero_bss(sbss, ebss);
+    }
+}
+
+/// Checks that every word of a memory region is zero.
+///
+/// This pairs with [`zero_bss`] in a power-on self-test, to confirm that `.bss` really reads as
+/// zero after it has been cleared. The region is read with volatile accesses.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region.
+/// - Use `T` to indicate the alignment of the region.
+///
+/// # Safety
+///
+/// - `end >= start`.
+/// - `start` and `end` must be `T` aligned.
+///
+/// # Errors
+///
+/// Returns the address of the first non-zero word.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn verify_zeroed<T>(mut start: *const T, end: *const T) -> Result<(), *const T>
+where
+    T: Word,
+{
+    while start < end {
+        if bytes_of(&ptr::read_volatile(start))
+            .iter()
+            .any(|&byte| byte != 0)
+        {
+            return Err(start);
+        }
+        start = start.offset(1);
+    }
+
+    Ok(())
+}
+
+/// Counts the non-zero words of a memory region.
+///
+/// Called right before [`zero_bss`] after a warm reset, this measures how much of `.bss` retained
+/// a value, which helps detect unexpected retention or corruption. The region is read with
+/// volatile accesses.
+///
+/// [`zero_bss`]: fn.zero_bss.html
+///
+/// # Arguments
+///
+/// Same as [`verify_zeroed`].
+///
+/// [`verify_zeroed`]: fn.verify_zeroed.html
+///
+/// # Safety
+///
+/// Same as [`verify_zeroed`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn count_nonzero<T>(mut start: *const T, end: *const T) -> usize
+where
+    T: Word,
+{
+    let mut count = 0;
+    while start < end {
+        if bytes_of(&ptr::read_volatile(start))
+            .iter()
+            .any(|&byte| byte != 0)
+        {
+            count += 1;
+        }
+        start = start.offset(1);
+    }
+
+    count
+}
+
+/// Stamps every word of a memory region with a poison value.
+///
+/// This is for fault injection testing: poison a region, run the real initialization, then call
+/// [`assert_unpoisoned`] to find any word the initialization missed. The region is written with
+/// volatile accesses, as in [`fill`].
+///
+/// [`assert_unpoisoned`]: fn.assert_unpoisoned.html
+/// [`fill`]: fn.fill.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region (the value behind this pointer
+///   will not be modified).
+/// - `value`: The poison value. It should be one the initialization never writes.
+/// - Use `T` to indicate the alignment of the region.
+///
+/// # Safety
+///
+/// Same as [`fill`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn poison<T>(start: *mut T, end: *mut T, value: T)
+where
+    T: Word,
+{
+    fill(start, end, value);
+}
+
+/// Checks that no word of a memory region still holds the poison value written by [`poison`].
+///
+/// Unlike [`verify_zeroed`], which only checks for zero, this detects a region that an
+/// initialization routine missed or only partially wrote, whatever the routine writes. The
+/// region is read with volatile accesses.
+///
+/// [`poison`]: fn.poison.html
+/// [`verify_zeroed`]: fn.verify_zeroed.html
+///
+/// # Arguments
+///
+/// - `start`: Pointer to the start of the region.
+/// - `end`: Pointer to the open/non-inclusive end of the region.
+/// - `value`: The poison value passed to [`poison`].
+/// - Use `T` to indicate the alignment of the region.
+///
+/// # Safety
+///
+/// Same as [`verify_zeroed`].
+///
+/// # Errors
+///
+/// Returns the address of the first word that still holds the poison value.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn assert_unpoisoned<T>(
+    mut start: *const T,
+    end: *const T,
+    value: T,
+) -> Result<(), *const T>
+where
+    T: Word,
+{
+    while start < end {
+        if bytes_of(&ptr::read_volatile(start)) == bytes_of(&value) {
+            return Err(start);
+        }
+        start = start.offset(1);
+    }
+
+    Ok(())
+}
+
+/// Checks that the `.data` section in RAM still matches its LMA.
+///
+/// A bootloader can call this long after [`init_data`], right before jumping to the application,
+/// to confirm that `.data` has not been corrupted in the meantime. Unlike [`crc32_region`], this
+/// compares the two regions directly and needs no stored check value. Both regions are read with
+/// volatile accesses.
+///
+/// [`init_data`]: fn.init_data.html
+/// [`crc32_region`]: fn.crc32_region.html
+///
+/// # Arguments
+///
+/// Same as [`init_data`].
+///
+/// # Safety
+///
+/// - `edata >= sdata`.
+/// - `sdata`, `edata` and `sidata` must be `T` aligned.
+/// - `sidata` must be valid for reads of as many words as the `sdata -> edata` region.
+///
+/// # Returns
+///
+/// `true` if every word of the `.data` section equals the corresponding word of the LMA.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn data_matches_lma<T>(
+    mut sdata: *const T,
+    edata: *const T,
+    mut sidata: *const T,
+) -> bool
+where
+    T: Word,
+{
+    while sdata < edata {
+        if bytes_of(&ptr::read_volatile(sdata)) != bytes_of(&ptr::read_volatile(sidata)) {
+            return false;
+        }
+        sdata = sdata.offset(1);
+        sidata = sidata.offset(1);
+    }
+
+    true
+}
+
+/// Copies the interrupt vector table from flash to RAM.
+///
+/// Relocating the vector table to RAM lets handlers be patched at runtime, e.g. by pointing
+/// `VTOR` at the copy. The copy is a plain [`init_data`]; this function states the intent and, in
+/// debug builds, checks that `dst` meets the architectural alignment requirement: on Cortex-M the
+/// table must be aligned to its size rounded up to the next power of two, and to at least 128
+/// bytes.
+///
+/// [`init_data`]: fn.init_data.html
+///
+/// # Arguments
+///
+/// - `dst`: Pointer to the RAM location of the table.
+/// - `src`: Pointer to the table in flash.
+/// - `entries`: Number of entries (32-bit words) in the table, including the initial stack
+///   pointer.
+///
+/// # Safety
+///
+/// - Must be called before the RAM table is activated.
+/// - `dst` must be suitably aligned, as described above.
+/// - The `dst -> dst + entries` region must not overlap with the `src -> src + entries` region.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_vector_table(dst: *mut u32, src: *const u32, entries: usize) {
+    debug_assert!(
+        dst as usize & (vector_table_align(entries) - 1) == 0,
+        "`dst` is not aligned for a vector table"
+    );
+
+    init_data(dst, dst.add(entries), src);
+}
+
+/// Returns the alignment that a vector table of `entries` words requires on Cortex-M.
+fn vector_table_align(entries: usize) -> usize {
+    cmp::max(128, (entries * mem::size_of::<u32>()).next_power_of_two())
+}
+
+/// Maximum number of sections of each kind a [`RamInitializer`] can hold.
+///
+/// [`RamInitializer`]: struct.RamInitializer.html
+pub const RAM_INITIALIZER_CAPACITY: usize = 4;
+
+/// Batches the sections to initialize at startup, and initializes them in one go.
+///
+/// This keeps the whole RAM initialization sequence in one place:
+///
+/// ```no_run
+/// # use r0::{CapacityError, RamInitializer};
+/// unsafe fn before_main() -> Result<(), CapacityError> {
+///     extern "C" {
+///         static mut _sbss: u32;
+///         static mut _ebss: u32;
+///
+///         static mut _sdata: u32;
+///         static mut _edata: u32;
+///         static _sidata: u32;
+///
+///         static mut _sramfunc: u32;
+///         static mut _eramfunc: u32;
+///         static _siramfunc: u32;
+///     }
+///
+///     let mut init = RamInitializer::new();
+///     init.bss(&mut _sbss, &mut _ebss)?
+///         .data(&mut _sdata, &mut _edata, &_sidata)?
+///         .ramfunc(&mut _sramfunc, &mut _eramfunc, &_siramfunc)?;
+///     init.run();
+///     Ok(())
+/// }
+/// ```
+///
+/// [`run`] zeroes all `.bss` sections first, then initializes the `.data` sections, then copies
+/// the RAM functions, each in registration order. The sections are stored inline, so at most
+/// [`RAM_INITIALIZER_CAPACITY`] sections of each kind can be registered.
+///
+/// [`run`]: struct.RamInitializer.html#method.run
+/// [`RAM_INITIALIZER_CAPACITY`]: constant.RAM_INITIALIZER_CAPACITY.html
+pub struct RamInitializer {
+    bss: [(*mut u32, *mut u32); RAM_INITIALIZER_CAPACITY],
+    nbss: usize,
+    data: [(*mut u32, *mut u32, *const u32); RAM_INITIALIZER_CAPACITY],
+    ndata: usize,
+    ramfunc: [(*mut u32, *mut u32, *const u32); RAM_INITIALIZER_CAPACITY],
+    nramfunc: usize,
+}
+
+impl RamInitializer {
+    /// Creates an initializer with no sections.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new() -> Self {
+        RamInitializer {
+            bss: [(ptr::null_mut(), ptr::null_mut()); RAM_INITIALIZER_CAPACITY],
+            nbss: 0,
+            data: [(ptr::null_mut(), ptr::null_mut(), ptr::null()); RAM_INITIALIZER_CAPACITY],
+            ndata: 0,
+            ramfunc: [(ptr::null_mut(), ptr::null_mut(), ptr::null()); RAM_INITIALIZER_CAPACITY],
+            nramfunc: 0,
+        }
+    }
+
+    /// Registers a `.bss` section, to be zeroed with [`zero_bss`].
+    ///
+    /// [`zero_bss`]: fn.zero_bss.html
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if [`RAM_INITIALIZER_CAPACITY`] `.bss` sections have already
+    /// been registered.
+    ///
+    /// [`CapacityError`]: struct.CapacityError.html
+    /// [`RAM_INITIALIZER_CAPACITY`]: constant.RAM_INITIALIZER_CAPACITY.html
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn bss(&mut self, sbss: *mut u32, ebss: *mut u32) -> Result<&mut Self, CapacityError> {
+        if self.nbss == RAM_INITIALIZER_CAPACITY {
+            return Err(CapacityError);
+        }
+        self.bss[self.nbss] = (sbss, ebss);
+        self.nbss += 1;
+        Ok(self)
+    }
+
+    /// Registers a `.data` section, to be initialized with [`init_data`].
+    ///
+    /// [`init_data`]: fn.init_data.html
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if [`RAM_INITIALIZER_CAPACITY`] `.data` sections have already
+    /// been registered.
+    ///
+    /// [`CapacityError`]: struct.CapacityError.html
+    /// [`RAM_INITIALIZER_CAPACITY`]: constant.RAM_INITIALIZER_CAPACITY.html
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn data(
+        &mut self,
+        sdata: *mut u32,
+        edata: *mut u32,
+        sidata: *const u32,
+    ) -> Result<&mut Self, CapacityError> {
+        if self.ndata == RAM_INITIALIZER_CAPACITY {
+            return Err(CapacityError);
+        }
+        self.data[self.ndata] = (sdata, edata, sidata);
+        self.ndata += 1;
+        Ok(self)
+    }
+
+    /// Registers a RAM code section, to be copied with [`init_ramfunc`].
+    ///
+    /// [`init_ramfunc`]: fn.init_ramfunc.html
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if [`RAM_INITIALIZER_CAPACITY`] RAM code sections have already
+    /// been registered.
+    ///
+    /// [`CapacityError`]: struct.CapacityError.html
+    /// [`RAM_INITIALIZER_CAPACITY`]: constant.RAM_INITIALIZER_CAPACITY.html
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn ramfunc(
+        &mut self,
+        sfunc: *mut u32,
+        efunc: *mut u32,
+        sifunc: *const u32,
+    ) -> Result<&mut Self, CapacityError> {
+        if self.nramfunc == RAM_INITIALIZER_CAPACITY {
+            return Err(CapacityError);
+        }
+        self.ramfunc[self.nramfunc] = (sfunc, efunc, sifunc);
+        self.nramfunc += 1;
+        Ok(self)
+    }
+
+    /// Initializes all the registered sections.
+    ///
+    /// # Safety
+    ///
+    /// - Must be called exactly once, before the application has started.
+    /// - Every `.bss` section must satisfy the safety requirements of [`zero_bss`], every `.data`
+    ///   section those of [`init_data`], and every RAM code section those of [`init_ramfunc`].
+    ///
+    /// [`zero_bss`]: fn.zero_bss.html
+    /// [`init_data`]: fn.init_data.html
+    /// [`init_ramfunc`]: fn.init_ramfunc.html
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub unsafe fn run(self) {
+        for &(sbss, ebss) in &self.bss[..self.nbss] {
+            zero_bss(sbss, ebss);
+        }
+
+        for &(sdata, edata, sidata) in &self.data[..self.ndata] {
+            init_data(sdata, edata, sidata);
+        }
+
+        for &(sfunc, efunc, sifunc) in &self.ramfunc[..self.nramfunc] {
+            init_ramfunc(sfunc, efunc, sifunc);
+        }
+    }
+}
+
+impl Default for RamInitializer {
+    fn default() -> Self {
+        RamInitializer::new()
+    }
+}
+
+/// Error returned by [`SectionList::push`] and the [`RamInitializer`] methods when the list of
+/// sections is full.
+///
+/// [`SectionList::push`]: struct.SectionList.html#method.push
+/// [`RamInitializer`]: struct.RamInitializer.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityError;
+
+/// A fixed-capacity list of [`SectionDesc`]s, collected and then initialized in one go.
+///
+/// This is an allocation-free way to gather the sections to initialize from several places, e.g.
+/// when each driver registers its own RAM regions:
+///
+/// ```no_run
+/// # use r0::{SectionDesc, SectionList};
+/// # fn driver_sections() -> [SectionDesc; 2] { [SectionDesc::zeroed(0, 0); 2] }
+/// unsafe fn before_main() {
+///     let mut sections = SectionList::<8>::new();
+///     for &section in &driver_sections() {
+///         sections.push(section).expect("too many sections");
+///     }
+///     sections.run();
+/// }
+/// ```
+///
+/// The sections are stored inline, so at most `N` sections can be pushed.
+///
+/// [`SectionDesc`]: struct.SectionDesc.html
+pub struct SectionList<const N: usize> {
+    sections: [SectionDesc; N],
+    len: usize,
+}
+
+impl<const N: usize> SectionList<N> {
+    /// Creates an empty list.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub const fn new() -> Self {
+        SectionList {
+            sections: [SectionDesc::zeroed(0, 0); N],
+            len: 0,
+        }
+    }
+
+    /// Appends a section to the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the list already holds `N` sections.
+    ///
+    /// [`CapacityError`]: struct.CapacityError.html
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn push(&mut self, section: SectionDesc) -> Result<(), CapacityError> {
+        if self.len == N {
+            return Err(CapacityError);
+        }
+        self.sections[self.len] = section;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the number of sections in the list.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list holds no sections.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Initializes all the sections of the list, in order, with [`run_sections`].
+    ///
+    /// [`run_sections`]: fn.run_sections.html
+    ///
+    /// # Safety
+    ///
+    /// Same as [`run_sections`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub unsafe fn run(self) {
+        run_sections(&self.sections[..self.len]);
+    }
+}
+
+impl<const N: usize> Default for SectionList<N> {
+    fn default() -> Self {
+        SectionList::new()
+    }
+}
+
+/// Initializes the `.data` section as 64-bit words, whatever its alignment.
+///
+/// On buses with a 64-bit data path, copying `u64`s halves the number of transfers compared to
+/// `u32`s. This function copies the bytes before the first 8-byte boundary of the destination
+/// individually, then the bulk of the section as `u64`s, then the remaining trailing bytes
+/// individually. The destination of every `u64` store is 8-byte aligned; the source is read
+/// with unaligned loads, so `sidata` does not need to share the alignment of `sdata` (though
+/// the copy is fastest when it does).
+///
+/// # Arguments
+///
+/// - `sdata`: Pointer to the start of the `.data` section in RAM.
+/// - `edata`: Pointer to the open/non-inclusive end of the `.data` section in
+///   RAM (the value behind this pointer will not be modified).
+/// - `sidata`: `.data` section Load Memory Address (LMA). Data will be copied
+///   from here.
+///
+/// # Safety
+///
+/// - Must be called exactly once, before the application has started.
+/// - `edata >= sdata`.
+/// - The `sdata -> edata` region must not overlap with the `sidata -> ...`
+///   region.
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_wide(sdata: *mut u8, edata: *mut u8, sidata: *const u8) {
+    let len = region_words(sdata, edata);
+    let head = cmp::min((sdata as usize).wrapping_neg() & 7, len);
+    let words = (len - head) / mem::size_of::<u64>();
+    let tail = len - head - words * mem::size_of::<u64>();
+
+    copy_bytes(sdata, sidata, head);
+
+    let (mut dst, mut src) = (sdata.add(head) as *mut u64, sidata.add(head) as *const u64);
+    for _ in 0..words {
+        ptr::write(dst, ptr::read_unaligned(src));
+        dst = dst.offset(1);
+        src = src.offset(1);
+    }
+
+    copy_bytes(dst as *mut u8, src as *const u8, tail);
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Initializes the `.data` section with the widest aligned accesses its layout allows.
+///
+/// When `sdata` and `sidata` share the same offset from a [`NativeWord`] boundary, the bytes
+/// before the first boundary are copied individually, the bulk of the section as aligned
+/// `NativeWord`s, and the remaining trailing bytes individually again. When the offsets differ,
+/// no aligned wide access is possible on both sides, and the whole section is copied one byte at
+/// a time. Unlike [`init_data_wide`], no unaligned loads are ever issued, which makes this
+/// suitable for memories and cores that do not support them.
+///
+/// [`NativeWord`]: type.NativeWord.html
+/// [`init_data_wide`]: fn.init_data_wide.html
+///
+/// # Arguments
+///
+/// Same as [`init_data_wide`].
+///
+/// # Safety
+///
+/// Same as [`init_data_wide`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn init_data_opt(sdata: *mut u8, edata: *mut u8, sidata: *const u8) {
+    const MASK: usize = mem::size_of::<NativeWord>() - 1;
+
+    let len = region_words(sdata, edata);
+    if (sdata as usize ^ sidata as usize) & MASK != 0 {
+        copy_bytes(sdata, sidata, len);
+    } else {
+        let head = cmp::min((sdata as usize).wrapping_neg() & MASK, len);
+        let words = (len - head) / mem::size_of::<NativeWord>();
+        let body = words * mem::size_of::<NativeWord>();
+
+        copy_bytes(sdata, sidata, head);
+        // NOTE a section shorter than its head does not reach a word boundary
+        if words != 0 {
+            copy_region(
+                sdata.add(head) as *mut NativeWord,
+                sidata.add(head) as *const NativeWord,
+                words,
+            );
+        }
+        copy_bytes(
+            sdata.add(head + body),
+            sidata.add(head + body),
+            len - head - body,
+        );
+    }
+
+    // See `copy_region`
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Copies `count` bytes, one at a time.
+unsafe fn copy_bytes(mut dst: *mut u8, mut src: *const u8, count: usize) {
+    for _ in 0..count {
+        ptr::write(dst, ptr::read(src));
+        dst = dst.offset(1);
+        src = src.offset(1);
+    }
 }
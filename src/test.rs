@@ -1,4 +1,32 @@
-use crate::{init_data, zero_bss};
+use core::{mem, ptr};
+
+#[cfg(target_pointer_width = "32")]
+use crate::apply_reloc_table;
+use crate::{
+    apply_chunks, apply_data_delta, apply_pokes, assert_unpoisoned, bytes_of, check_stack_guard,
+    copy_region, count_nonzero, crc32_region, data_matches_lma, ecc_prime, fill, fill_bytes,
+    fill_bytes_volatile, fill_with_filler, heap_init, init_all, init_copy_table, init_data,
+    init_data16, init_data8, init_data_aligned, init_data_bswap, init_data_bytes,
+    init_data_bytes_count, init_data_checked, init_data_checksummed, init_data_committed,
+    init_data_fast, init_data_from_const, init_data_gen, init_data_len, init_data_lz4,
+    init_data_native, init_data_opt, init_data_overlapping, init_data_profiled, init_data_range,
+    init_data_readback, init_data_regions, init_data_reported, init_data_ret, init_data_rle,
+    init_data_split, init_data_typed, init_data_verified, init_data_volatile_src, init_data_wide,
+    init_data_xor, init_fill_table, init_ramfunc, init_readback, init_section, init_slice_from,
+    init_vector_table, init_verified, init_with, install_stack_guard, march_c, march_c_test,
+    paint_stack, poison, poison_indexed, region_words, run_sections, scrub, set_region,
+    stack_free_words, verify_zeroed, zero_bss, zero_bss16, zero_bss8, zero_bss_auto,
+    zero_bss_banked, zero_bss_burst, zero_bss_bytes, zero_bss_cache, zero_bss_except,
+    zero_bss_fast, zero_bss_kick, zero_bss_len, zero_bss_n, zero_bss_native, zero_bss_range,
+    zero_bss_release, zero_bss_reverse, zero_bss_scrubbed, zero_bss_sparse, zero_bss_traced,
+    zero_bss_via, zero_bss_with, zero_bss_with_filler, zero_gap, zero_slice, zero_sparse,
+    zero_table, Access, AssertNotZeroSized, BssZeroProgress, BssZeroer, CapacityError, Committer,
+    CpuFiller, CpuZeroer, DataInitReport, InitError, Lma, MemFiller, NativeWord, RamCommitter,
+    RamInitializer, Region, RelocRecord, SectionDesc, SectionKind, SectionList, Vma, Volatile,
+    Word, PROGRESS_INTERVAL, RAM_INITIALIZER_CAPACITY,
+};
+#[cfg(feature = "wide-words")]
+use crate::{init_data64, zero_bss64};
 
 #[test]
 fn test_init_data() {
@@ -6,22 +34,2406 @@ fn test_init_data() {
     let mut mem = [0u32; 8];
     let sdata = data.as_mut_ptr();
     let sidata = mem.as_mut_ptr();
-    unsafe {
-        let edata = sdata.offset(data.len() as isize);
-        init_data::<u32>(sdata, edata, sidata);
+    let words = unsafe {
+        let edata = sdata.add(data.len());
+        init_data::<u32>(sdata, edata, sidata)
     };
 
     assert_eq!(data, mem);
+    assert_eq!(words, data.len());
+}
+
+#[test]
+fn test_ranges() {
+    let lma = [1u32, 2, 3, 4];
+    let mut data = [0u32; 4];
+    let mut bss = [0xFFFF_FFFFu32; 4];
+    let (copied, zeroed) = unsafe {
+        let sdata = data.as_mut_ptr();
+        let sbss = bss.as_mut_ptr();
+        (
+            init_data_range(sdata..sdata.add(data.len()), lma.as_ptr()),
+            zero_bss_range(sbss..sbss.add(bss.len())),
+        )
+    };
+
+    assert_eq!(data, lma);
+    assert_eq!(bss, [0; 4]);
+    assert_eq!((copied, zeroed), (4, 4));
+}
+
+#[test]
+fn test_init_data_typed() {
+    let lma = [1u32, 2, 3, 4];
+    let mut data = [0u32; 4];
+    let words = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_typed(
+            Vma::new(sdata),
+            Vma::new(sdata.add(data.len())),
+            Lma::new(lma.as_ptr()),
+        )
+    };
+
+    assert_eq!(data, lma);
+    assert_eq!(words, 4);
+}
+
+#[test]
+fn test_byte_counts() {
+    let lma = [1u32, 2, 3];
+    let mut data = [0u32; 3];
+    let mut bss = [!0u16; 5];
+    let (copied, zeroed) = unsafe {
+        let sdata = data.as_mut_ptr();
+        let sbss = bss.as_mut_ptr();
+        (
+            init_data_bytes_count(sdata, sdata.add(data.len()), lma.as_ptr()),
+            zero_bss_bytes(sbss, sbss.add(bss.len())),
+        )
+    };
+
+    assert_eq!(data, lma);
+    assert_eq!(bss, [0; 5]);
+    assert_eq!(copied, mem::size_of_val(&data));
+    assert_eq!(zeroed, mem::size_of_val(&bss));
+}
+
+#[test]
+fn test_init_data_ret() {
+    let lma = [1u32, 2, 3];
+    let mut data = [0u32; 3];
+    let (end, edata) = unsafe {
+        let sdata = data.as_mut_ptr();
+        let edata = sdata.add(data.len());
+        (init_data_ret(sdata, edata, lma.as_ptr()), edata)
+    };
+
+    assert_eq!(end, edata);
+    assert_eq!(data, lma);
+}
+
+#[test]
+fn test_init_data_profiled() {
+    let lma = [1u16, 2, 3, 4, 5];
+    let mut data = [0u16; 5];
+    let mut reported = None;
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_profiled(sdata, sdata.add(data.len()), lma.as_ptr(), |words| {
+            reported = Some(words);
+        });
+    }
+
+    assert_eq!(reported, Some(5));
+    assert_eq!(data, lma);
+}
+
+#[test]
+fn test_slices() {
+    let lma = [1u32, 2, 3, 4];
+    let mut data = [0u32; 6];
+    init_slice_from(&mut data[1..5], &lma);
+    assert_eq!(data, [0, 1, 2, 3, 4, 0]);
+
+    zero_slice(&mut data[2..]);
+    assert_eq!(data, [0, 1, 0, 0, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "`dst` and `src` have different lengths")]
+fn test_init_slice_from_mismatch() {
+    init_slice_from(&mut [0u32; 4], &[1, 2, 3]);
+}
+
+#[test]
+fn test_copy_region() {
+    // Gain and offset pairs, as found in a calibration table
+    let table = [
+        0x0001_0000u32,
+        0xFFFF_FFF6,
+        0x0000_F333,
+        0x0000_0042,
+        0x0001_0CCD,
+        0,
+    ];
+    let mut ram = [0u32; 8];
+    unsafe { copy_region(ram.as_mut_ptr(), table.as_ptr(), table.len()) }
+
+    assert_eq!(ram[..table.len()], table);
+    assert_eq!(ram[table.len()..], [0, 0]);
 }
 
 #[test]
 fn test_zero_bss() {
     let mut mem = [0xFFFF_FFFFu32; 8];
     let sbss = mem.as_mut_ptr();
-    unsafe {
-        let ebss = sbss.offset(mem.len() as isize);
-        zero_bss::<u32>(sbss, ebss);
+    let words = unsafe {
+        let ebss = sbss.add(mem.len());
+        zero_bss::<u32>(sbss, ebss)
     };
 
     assert_eq!(mem, [0u32; 8]);
+    assert_eq!(words, mem.len());
+}
+
+#[test]
+fn test_fill() {
+    let mut mem = [0u32; 8];
+    let start = mem.as_mut_ptr();
+    unsafe {
+        let end = start.add(mem.len());
+        fill::<u32>(start, end, 0xAAAA_AAAA);
+    };
+
+    assert_eq!(mem, [0xAAAA_AAAAu32; 8]);
+}
+
+#[test]
+fn test_set_region() {
+    let mut mem = [0u16; 7];
+    let start = mem.as_mut_ptr();
+    unsafe {
+        set_region::<u16, true>(start, start.add(4), 0x1234);
+        set_region::<u16, false>(start.add(4), start.add(7), 0xABCD);
+    }
+
+    assert_eq!(
+        mem,
+        [0x1234, 0x1234, 0x1234, 0x1234, 0xABCD, 0xABCD, 0xABCD]
+    );
+
+    unsafe {
+        set_region::<u16, false>(start, start, 0);
+    }
+    assert_eq!(mem[0], 0x1234);
+}
+
+#[test]
+fn test_fill_bytes() {
+    let mut mem = [0u8; 13];
+    unsafe {
+        let start = mem.as_mut_ptr();
+        fill_bytes(start.add(1), start.add(12), 0xFF);
+    }
+
+    assert_eq!(mem[0], 0);
+    assert!(mem[1..12].iter().all(|&byte| byte == 0xFF));
+    assert_eq!(mem[12], 0);
+
+    let mut mem = [0u8; 13];
+    unsafe {
+        let start = mem.as_mut_ptr();
+        fill_bytes_volatile(start, start.add(mem.len()), 0xFF);
+    }
+
+    assert_eq!(mem, [0xFF; 13]);
+}
+
+#[test]
+fn test_poison_indexed() {
+    let mut mem = [0u32; 8];
+    unsafe {
+        let start = mem.as_mut_ptr();
+        poison_indexed(start, start.add(mem.len()));
+    }
+
+    for (n, &word) in mem.iter().enumerate() {
+        assert_eq!(word, n as u32);
+    }
+}
+
+#[test]
+fn test_init_fill_table() {
+    let mut mem = [0u32; 8];
+    unsafe { init_fill_table(mem.as_mut_ptr(), &[(0xFFFF_FFFF, 3), (0x5555_AAAA, 4)]) }
+
+    assert_eq!(
+        mem,
+        [
+            0xFFFF_FFFF,
+            0xFFFF_FFFF,
+            0xFFFF_FFFF,
+            0x5555_AAAA,
+            0x5555_AAAA,
+            0x5555_AAAA,
+            0x5555_AAAA,
+            0,
+        ]
+    );
+}
+
+#[test]
+fn test_apply_pokes() {
+    let mut mem = [!0u32; 6];
+    zero_slice(&mut mem);
+    unsafe {
+        apply_pokes(
+            mem.as_mut_ptr() as *mut u8,
+            &[(4, 0x0000_0100), (16, 0xCAFE_F00D)],
+        )
+    }
+
+    assert_eq!(mem, [0, 0x0000_0100, 0, 0, 0xCAFE_F00D, 0]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "poke at offset 6 is not aligned")]
+fn test_apply_pokes_misaligned() {
+    let mut mem = [0u32; 4];
+    unsafe { apply_pokes(mem.as_mut_ptr() as *mut u8, &[(6, 1)]) }
+}
+
+#[test]
+fn test_ecc_prime() {
+    let mut mem = [0x5A5A_5A5Au32; 8];
+    unsafe {
+        let start = mem.as_mut_ptr().add(2);
+        ecc_prime(start, start.add(4));
+    }
+
+    assert_eq!(
+        mem,
+        [
+            0x5A5A_5A5A,
+            0x5A5A_5A5A,
+            0,
+            0,
+            0,
+            0,
+            0x5A5A_5A5A,
+            0x5A5A_5A5A
+        ]
+    );
+}
+
+#[test]
+fn test_heap_init() {
+    let mut mem = [0x5A5A_5A5Au32; 8];
+    let sheap = mem.as_mut_ptr() as *mut u8;
+    unsafe {
+        let eheap = sheap.add(32);
+        assert_eq!(heap_init(sheap, eheap, false), (sheap as usize, 32));
+        assert_eq!(*sheap, 0x5A);
+        assert_eq!(heap_init(sheap, eheap, true), (sheap as usize, 32));
+    }
+
+    assert_eq!(mem, [0; 8]);
+}
+
+#[test]
+fn test_init_copy_table() {
+    let flash_a = [1u32, 2, 3, 4];
+    let flash_b = [5u32, 6];
+    let mut ram_a = [0u32; 4];
+    let mut ram_b = [0u32; 2];
+    let table = [
+        flash_a.as_ptr() as usize,
+        ram_a.as_mut_ptr() as usize,
+        ram_a.len(),
+        flash_b.as_ptr() as usize,
+        ram_b.as_mut_ptr() as usize,
+        ram_b.len(),
+    ];
+    unsafe {
+        init_copy_table(table.as_ptr(), table.as_ptr().add(table.len()));
+    }
+
+    assert_eq!(ram_a, flash_a);
+    assert_eq!(ram_b, flash_b);
+}
+
+#[test]
+fn test_reloc_record_layout() {
+    // The table a build tool emits: flat `u32` triples, whatever the pointer width
+    static TABLE: [RelocRecord; 2] = [
+        RelocRecord {
+            dst: 0x2000_0000,
+            src: 0x0800_4000,
+            len_bytes: 0x100,
+        },
+        RelocRecord {
+            dst: 0x2000_0100,
+            src: 0x0800_4100,
+            len_bytes: 6,
+        },
+    ];
+
+    assert_eq!(mem::size_of::<RelocRecord>(), 12);
+    let words = unsafe { ptr::read(TABLE.as_ptr() as *const [u32; 6]) };
+    assert_eq!(
+        words,
+        [0x2000_0000, 0x0800_4000, 0x100, 0x2000_0100, 0x0800_4100, 6]
+    );
+}
+
+// The records hold 32-bit addresses, so only 32-bit targets can point them at test buffers
+#[cfg(target_pointer_width = "32")]
+#[test]
+fn test_apply_reloc_table() {
+    let flash_a = [1u8, 2, 3, 4, 5];
+    let flash_b = [0xAAu8, 0xBB];
+    let mut ram_a = [0u8; 5];
+    let mut ram_b = [0u8; 3];
+    let table = [
+        RelocRecord {
+            dst: ram_a.as_mut_ptr() as u32,
+            src: flash_a.as_ptr() as u32,
+            len_bytes: flash_a.len() as u32,
+        },
+        RelocRecord {
+            dst: ram_b.as_mut_ptr() as u32 + 1,
+            src: flash_b.as_ptr() as u32,
+            len_bytes: flash_b.len() as u32,
+        },
+    ];
+    unsafe {
+        apply_reloc_table(table.as_ptr(), table.len());
+    }
+
+    assert_eq!(ram_a, flash_a);
+    assert_eq!(ram_b, [0, 0xAA, 0xBB]);
+}
+
+#[test]
+fn test_apply_chunks() {
+    let staging = [1u8, 2, 3, 4, 5, 0xAA, 0xBB];
+    let mut ram_a = [0u8; 5];
+    let mut ram_b = [0u8; 4];
+    let dst_b = ram_b.as_mut_ptr();
+    unsafe {
+        apply_chunks(&[
+            (ram_a.as_mut_ptr(), &staging[..5]),
+            (dst_b.add(1), &staging[5..]),
+            // Overlaps the previous chunk, and wins
+            (dst_b.add(2), &staging[..1]),
+        ]);
+    }
+
+    assert_eq!(ram_a, [1, 2, 3, 4, 5]);
+    assert_eq!(ram_b, [0, 0xAA, 1, 0]);
+}
+
+#[test]
+fn test_run_sections() {
+    // Descriptors are `const`-constructible, so a table can be a `static`
+    static SECTIONS: [SectionDesc; 2] = [
+        SectionDesc::new(0x2000_0000, 0x2000_0100, 0x0800_4000),
+        SectionDesc::zeroed(0x2000_0100, 0x2000_0400),
+    ];
+    assert_eq!(SECTIONS[0].lma, 0x0800_4000);
+    assert_eq!(SECTIONS[1].end, 0x2000_0400);
+    assert!(SECTIONS[1].zero);
+    // Zero is a valid LMA, and does not make a section zeroed
+    assert!(!SectionDesc::new(0x2000_0000, 0x2000_0100, 0).zero);
+
+    let lma = [1u32, 2, 3];
+    let mut data = [0u32; 3];
+    let mut bss = [!0u32; 2];
+    let (sdata, sbss) = (data.as_mut_ptr() as usize, bss.as_mut_ptr() as usize);
+    let table = [
+        SectionDesc::new(sdata, sdata + 12, lma.as_ptr() as usize),
+        SectionDesc::zeroed(sbss, sbss + 8),
+    ];
+    unsafe { run_sections(&table) }
+
+    assert_eq!(data, lma);
+    assert_eq!(bss, [0; 2]);
+}
+
+#[test]
+fn test_section_list() {
+    let lma = [1u32, 2, 3];
+    let mut data = [0u32; 3];
+    let mut bss = [!0u32; 2];
+    let (sdata, sbss) = (data.as_mut_ptr() as usize, bss.as_mut_ptr() as usize);
+
+    let mut sections = SectionList::<2>::new();
+    assert!(sections.is_empty());
+    assert_eq!(
+        sections.push(SectionDesc::new(sdata, sdata + 12, lma.as_ptr() as usize)),
+        Ok(())
+    );
+    assert_eq!(sections.push(SectionDesc::zeroed(sbss, sbss + 8)), Ok(()));
+    assert_eq!(
+        sections.push(SectionDesc::zeroed(sbss, sbss + 8)),
+        Err(CapacityError)
+    );
+    assert_eq!(sections.len(), 2);
+    unsafe { sections.run() }
+
+    assert_eq!(data, lma);
+    assert_eq!(bss, [0; 2]);
+}
+
+#[test]
+fn test_init_section() {
+    let lma = [1u32, 2, 3];
+    let mut mem = [0x5555_5555u32; 9];
+    let base = mem.as_mut_ptr() as *mut u8;
+    let map = [
+        (0, 12, SectionKind::Zero),
+        (
+            12,
+            24,
+            SectionKind::Copy {
+                lma: lma.as_ptr() as *const u8,
+            },
+        ),
+        (24, 36, SectionKind::Fill { value: !0 }),
+    ];
+    for &(start, end, kind) in &map {
+        unsafe { init_section(base.add(start), base.add(end), kind) }
+    }
+
+    assert_eq!(mem, [0, 0, 0, 1, 2, 3, !0, !0, !0]);
+}
+
+#[test]
+fn test_zero_table() {
+    let mut ram_a = [0xFFFF_FFFFu32; 4];
+    let mut ram_b = [0xFFFF_FFFFu32; 2];
+    let table = [
+        ram_a.as_mut_ptr() as usize,
+        ram_a.len(),
+        ram_b.as_mut_ptr() as usize,
+        ram_b.len(),
+    ];
+    unsafe {
+        zero_table(table.as_ptr(), table.as_ptr().add(table.len()));
+    }
+
+    assert_eq!(ram_a, [0u32; 4]);
+    assert_eq!(ram_b, [0u32; 2]);
+}
+
+#[test]
+fn test_usize_words() {
+    let mut data = [0usize; 8];
+    let mut mem = [0usize, 1, 2, 3, 4, 5, 6, !0];
+    let sdata = data.as_mut_ptr();
+    let sidata = mem.as_mut_ptr();
+    unsafe {
+        let edata = sdata.add(data.len());
+        init_data::<usize>(sdata, edata, sidata);
+    };
+    assert_eq!(data, mem);
+
+    let sbss = data.as_mut_ptr();
+    unsafe {
+        let ebss = sbss.add(data.len());
+        zero_bss::<usize>(sbss, ebss);
+    };
+    assert_eq!(data, [0usize; 8]);
+}
+
+#[test]
+fn test_init_data_fast() {
+    let mut flash = [0u32; 29];
+    for (i, word) in flash.iter_mut().enumerate() {
+        *word = (i as u32).wrapping_mul(0x9E37_79B9);
+    }
+    let mut slow = [0u32; 29];
+    let mut fast = [0u32; 29];
+    let (slow_words, fast_words) = unsafe {
+        let sslow = slow.as_mut_ptr();
+        let sfast = fast.as_mut_ptr();
+        (
+            init_data(sslow, sslow.add(slow.len()), flash.as_ptr()),
+            init_data_fast(sfast, sfast.add(fast.len()), flash.as_ptr()),
+        )
+    };
+
+    assert_eq!(fast, slow);
+    assert_eq!(fast, flash);
+    assert_eq!(fast_words, slow_words);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "`sdata` is not aligned")]
+fn test_init_data_misaligned() {
+    let mut data = [0u32; 4];
+    let mem = [0u32; 4];
+    unsafe {
+        let sdata = (data.as_mut_ptr() as *mut u8).add(1) as *mut u32;
+        let edata = (data.as_mut_ptr() as *mut u8).add(9) as *mut u32;
+        init_data(sdata, edata, mem.as_ptr());
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "`ebss` is not aligned")]
+fn test_zero_bss_misaligned() {
+    let mut mem = [0u32; 4];
+    unsafe {
+        let sbss = mem.as_mut_ptr();
+        let ebss = (sbss as *mut u8).add(6) as *mut u32;
+        zero_bss(sbss, ebss);
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "`edata` is below `sdata`")]
+fn test_init_data_reversed() {
+    let mut data = [0u32; 4];
+    let mem = [0u32; 4];
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data(sdata.add(data.len()), sdata, mem.as_ptr());
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "`ebss` is below `sbss`")]
+fn test_zero_bss_reversed() {
+    let mut mem = [0u32; 4];
+    unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_bss(sbss.add(mem.len()), sbss);
+    }
+}
+
+#[test]
+fn test_init_data_checked() {
+    let mut data = [0u32; 4];
+    let mem = [1u32, 2, 3, 4];
+    let sdata = data.as_mut_ptr();
+    let res = unsafe { init_data_checked(sdata, sdata.add(data.len()), mem.as_ptr()) };
+
+    assert_eq!(res, Ok(()));
+    assert_eq!(data, mem);
+}
+
+#[test]
+fn test_init_data_checked_errors() {
+    // offsets into a single buffer, so that the regions can be measured
+    let mut mem = [0u32; 0x2010 / 4];
+    let base = mem.as_mut_ptr() as *mut u8;
+    let at = |offset: usize| unsafe { base.add(offset) as *mut u32 };
+    let check = |sdata: usize, edata: usize, sidata: usize| unsafe {
+        init_data_checked(at(sdata), at(edata), at(sidata))
+    };
+
+    assert_eq!(check(0x1001, 0x1010, 0x2000), Err(InitError::Unaligned));
+    assert_eq!(check(0x1000, 0x1012, 0x2000), Err(InitError::Unaligned));
+    assert_eq!(check(0x1000, 0x1010, 0x2002), Err(InitError::Unaligned));
+    assert_eq!(check(0x1010, 0x1000, 0x2000), Err(InitError::BadRange));
+    assert_eq!(check(0x1000, 0x1010, 0x1008), Err(InitError::Overlap));
+    assert_eq!(check(0x1008, 0x1018, 0x1000), Err(InitError::Overlap));
+    assert_eq!(check(0x1000, 0x1010, 0x1000), Err(InitError::Overlap));
+}
+
+#[test]
+fn test_init_error_as_str_code() {
+    // Usable in constants, as they are `const fn`s
+    const CODE: u8 = InitError::Overlap.code();
+    assert_eq!(CODE, 3);
+
+    assert_eq!(InitError::Unaligned.as_str(), "pointer is not aligned");
+    assert_eq!(InitError::Unaligned.code(), 1);
+    assert_eq!(
+        InitError::BadRange.as_str(),
+        "region end is below its start"
+    );
+    assert_eq!(InitError::BadRange.code(), 2);
+    assert_eq!(
+        InitError::Overlap.as_str(),
+        "source and destination overlap"
+    );
+    assert_eq!(InitError::Overlap.code(), 3);
+}
+
+#[test]
+fn test_paint_stack() {
+    let mut stack = [0u32; 16];
+    let bottom = stack.as_mut_ptr();
+    unsafe {
+        paint_stack(bottom, bottom.add(stack.len()), 0xC0DE_C0DE);
+    }
+
+    assert_eq!(stack, [0xC0DE_C0DEu32; 16]);
+}
+
+#[test]
+fn test_stack_free_words() {
+    let mut stack = [0xC0DE_C0DEu32; 16];
+    let free = |stack: &[u32]| unsafe {
+        let bottom = stack.as_ptr();
+        stack_free_words(bottom, bottom.add(stack.len()), 0xC0DE_C0DE)
+    };
+    assert_eq!(free(&stack), 16);
+
+    // Simulate the stack growing down to the 11th word
+    for word in &mut stack[10..] {
+        *word = 0;
+    }
+    assert_eq!(free(&stack), 10);
+
+    stack[0] = 0;
+    assert_eq!(free(&stack), 0);
+}
+
+#[test]
+fn test_init_data_rle() {
+    let expected = [1u8, 1, 1, 1, 1, 2, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 9];
+    let compressed = [0x84u8, 1, 0x02, 2, 3, 4, 0x87, 0, 0x00, 9];
+    let mut data = [0xFFu8; 17];
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_rle(sdata, sdata.add(data.len()), compressed.as_ptr());
+    }
+    assert_eq!(data, expected);
+
+    // Runs that extend past the end of the section are truncated
+    let mut data = [0xFFu8; 6];
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_rle(sdata, sdata.add(data.len()), compressed.as_ptr());
+    }
+    assert_eq!(data, expected[..6]);
+}
+
+#[test]
+fn test_crc32_region() {
+    let crc = |bytes: &[u8]| unsafe {
+        let start = bytes.as_ptr();
+        crc32_region(start, start.add(bytes.len()))
+    };
+
+    assert_eq!(crc(b""), 0);
+    assert_eq!(crc(b"123456789"), 0xCBF4_3926);
+    assert_eq!(
+        crc(b"The quick brown fox jumps over the lazy dog"),
+        0x414F_A339
+    );
+
+    // The result only depends on the bytes of the region, not on the word type
+    let words = [0x1234_5678u32, 0x9ABC_DEF0];
+    let bytes = unsafe { core::slice::from_raw_parts(words.as_ptr() as *const u8, 8) };
+    let word_crc = unsafe { crc32_region(words.as_ptr(), words.as_ptr().add(words.len())) };
+    assert_eq!(word_crc, crc(bytes));
+}
+
+#[test]
+fn test_region() {
+    static LMA: [u32; 4] = [1, 2, 3, 4];
+    let mut bss = [0xFFFF_FFFFu32; 8];
+    let mut data = [0u32; 4];
+
+    unsafe {
+        let start = bss.as_mut_ptr();
+        let region = Region::from_symbols(start, start.add(bss.len()));
+        assert_eq!(region.zero(), 8);
+
+        let start = data.as_mut_ptr();
+        let region = Region::from_symbols(start, start.add(data.len()));
+        assert_eq!(region.init_from(LMA.as_ptr()), 4);
+    }
+
+    assert_eq!(bss, [0u32; 8]);
+    assert_eq!(data, LMA);
+}
+
+#[test]
+fn test_region_addresses() {
+    let mut mem = [0u16; 5];
+    let start = mem.as_mut_ptr();
+    let region = unsafe { Region::from_symbols(start, start.add(mem.len())) };
+
+    let mut count = 0;
+    for (i, addr) in region.addresses().enumerate() {
+        assert_eq!(addr as usize - start as usize, i * mem::size_of::<u16>());
+        count += 1;
+    }
+    assert_eq!(count, 5);
+
+    let region = unsafe { Region::from_symbols(start, start) };
+    assert_eq!(region.addresses().count(), 0);
+}
+
+/// Memory with a single bit that flips whenever the word holding it is written.
+struct FlipBit {
+    byte: *mut u8,
+}
+
+impl Access for FlipBit {
+    unsafe fn read<T>(&mut self, src: *const T) -> T
+    where
+        T: Word,
+    {
+        Volatile.read(src)
+    }
+
+    unsafe fn write<T>(&mut self, dst: *mut T, value: T)
+    where
+        T: Word,
+    {
+        Volatile.write(dst, value);
+
+        let start = dst as *mut u8;
+        if start <= self.byte && self.byte < start.add(mem::size_of::<T>()) {
+            *self.byte ^= 0x08;
+        }
+    }
+}
+
+/// Memory that counts the writes made to it.
+struct CountWrites {
+    writes: usize,
+}
+
+impl Access for CountWrites {
+    unsafe fn read<T>(&mut self, src: *const T) -> T
+    where
+        T: Word,
+    {
+        Volatile.read(src)
+    }
+
+    unsafe fn write<T>(&mut self, dst: *mut T, value: T)
+    where
+        T: Word,
+    {
+        Volatile.write(dst, value);
+        self.writes += 1;
+    }
+}
+
+#[test]
+fn test_zero_sparse() {
+    let mut mem = [0u32; 8];
+    let mut counter = CountWrites { writes: 0 };
+    unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_sparse(&mut counter, sbss, sbss.add(mem.len()));
+    }
+
+    assert_eq!(mem, [0; 8]);
+    assert_eq!(counter.writes, 0);
+
+    let mut mem = [0, 1, 0, 0x8000_0000, 0, 0, 0xFFFF_FFFFu32, 0];
+    unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_sparse(&mut counter, sbss, sbss.add(mem.len()));
+    }
+
+    assert_eq!(mem, [0; 8]);
+    assert_eq!(counter.writes, 3);
+}
+
+#[test]
+fn test_zero_bss_kick() {
+    for &(interval, kicks) in &[(1, 24), (4, 6), (5, 4), (24, 1), (25, 0)] {
+        let mut mem = [!0u16; 24];
+        let mut kicked = 0;
+        let words = unsafe {
+            let sbss = mem.as_mut_ptr();
+            zero_bss_kick(sbss, sbss.add(mem.len()), interval, || kicked += 1)
+        };
+
+        assert_eq!(mem, [0; 24], "interval of {}", interval);
+        assert_eq!(kicked, kicks, "interval of {}", interval);
+        assert_eq!(words, 24);
+    }
+}
+
+#[test]
+fn test_zero_bss_cache() {
+    let mut mem = [!0u32; 6];
+    let sbss = mem.as_mut_ptr();
+    let mut calls = 0;
+    let words = unsafe {
+        zero_bss_cache(sbss, sbss.add(6), |start, words| {
+            assert_eq!((start, words), (sbss, 6));
+            // The region must already be zeroed
+            assert_eq!(*start.add(5), 0);
+            calls += 1;
+        })
+    };
+
+    assert_eq!(calls, 1);
+    assert_eq!(words, 6);
+    assert_eq!(mem, [0; 6]);
+}
+
+#[test]
+fn test_zero_bss_release() {
+    let mut mem = [!0u32; 6];
+    let sbss = mem.as_mut_ptr();
+    let words = unsafe { zero_bss_release(sbss, sbss.add(6)) };
+
+    assert_eq!(words, 6);
+    assert_eq!(mem, [0; 6]);
+}
+
+#[test]
+fn test_zero_bss_traced() {
+    let mut mem = [!0u32; 6];
+    let sbss = mem.as_mut_ptr();
+    let range = unsafe { zero_bss_traced(sbss, sbss.add(6)) };
+
+    assert_eq!(range, (sbss as usize, sbss as usize + 23));
+    assert_eq!(mem, [0; 6]);
+
+    assert_eq!(unsafe { zero_bss_traced(sbss, sbss) }, (0, 0));
+}
+
+#[test]
+fn test_bss_zero_progress() {
+    let mut mem = [!0u32; 10];
+    let mut steps = 0;
+    unsafe {
+        let sbss = mem.as_mut_ptr();
+        let mut bss = BssZeroProgress::new(sbss.add(1), sbss.add(9));
+        while !bss.step(3) {
+            steps += 1;
+            assert_eq!(*sbss.add(steps * 3), 0);
+            assert_eq!(*sbss.add(steps * 3 + 1), !0);
+        }
+
+        // Stepping a completed zeroing is a no-op
+        assert!(bss.step(3));
+    }
+
+    assert_eq!(steps, 2);
+    assert_eq!(mem, [!0, 0, 0, 0, 0, 0, 0, 0, 0, !0]);
+}
+
+#[test]
+fn test_zero_bss_reverse() {
+    let mut forward = [1u32, 2, 3, 4, 5, 6, 7, 8];
+    let mut reverse = forward;
+    zero_slice(&mut forward[1..7]);
+    unsafe {
+        let sbss = reverse.as_mut_ptr().add(1);
+        zero_bss_reverse(sbss, sbss.add(6));
+    }
+
+    assert_eq!(reverse, forward);
+    assert_eq!(reverse, [1, 0, 0, 0, 0, 0, 0, 8]);
+}
+
+#[test]
+fn test_zero_bss_burst() {
+    for &burst in &[1, 2, 4, 3, 5, 12, 100] {
+        let mut mem = [!0u32; 14];
+        let words = unsafe {
+            let sbss = mem.as_mut_ptr().add(1);
+            zero_bss_burst(sbss, sbss.add(12), burst)
+        };
+
+        assert_eq!(mem[0], !0, "burst of {}", burst);
+        assert_eq!(mem[1..13], [0; 12], "burst of {}", burst);
+        assert_eq!(mem[13], !0, "burst of {}", burst);
+        assert_eq!(words, 12);
+    }
+}
+
+#[test]
+#[should_panic(expected = "`burst_words` is zero")]
+fn test_zero_bss_burst_zero() {
+    let mut mem = [0u32; 4];
+    unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_bss_burst(sbss, sbss.add(mem.len()), 0);
+    }
+}
+
+#[test]
+fn test_zero_bss_sparse() {
+    let mut mem = [0xFFu8, 0, 0x10, 0];
+    unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_bss_sparse(sbss, sbss.add(mem.len()));
+    }
+
+    assert_eq!(mem, [0; 4]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "`ebss` is below `sbss`")]
+fn test_zero_bss_sparse_reversed() {
+    let mut mem = [0u32; 4];
+    unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_bss_sparse(sbss.add(mem.len()), sbss);
+    }
+}
+
+/// Flash with a single bit that reads back flipped on every read after the first one.
+struct ReadDisturb {
+    byte: *const u8,
+    reads: usize,
+}
+
+impl Access for ReadDisturb {
+    unsafe fn read<T>(&mut self, src: *const T) -> T
+    where
+        T: Word,
+    {
+        let mut value = Volatile.read(src);
+
+        let start = src as *const u8;
+        if start <= self.byte && self.byte < start.add(mem::size_of::<T>()) {
+            if self.reads != 0 {
+                let offset = self.byte as usize - start as usize;
+                *(&mut value as *mut T as *mut u8).add(offset) ^= 0x08;
+            }
+            self.reads += 1;
+        }
+
+        value
+    }
+
+    unsafe fn write<T>(&mut self, dst: *mut T, value: T)
+    where
+        T: Word,
+    {
+        Volatile.write(dst, value)
+    }
+}
+
+#[test]
+fn test_init_data_verified() {
+    let lma = [1u32, 2, 3, 4];
+    let mut data = [0u32; 4];
+    let res = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_verified(sdata, sdata.add(data.len()), lma.as_ptr())
+    };
+
+    assert_eq!(res, Ok(()));
+    assert_eq!(data, lma);
+}
+
+#[test]
+fn test_init_verified_mismatch() {
+    let lma = [1u32, 2, 3, 4];
+    let mut data = [0u32; 4];
+    let mut flash = ReadDisturb {
+        byte: unsafe { (lma.as_ptr() as *const u8).add(9) },
+        reads: 0,
+    };
+    let res = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_verified(&mut flash, sdata, sdata.add(data.len()), lma.as_ptr())
+    };
+
+    assert_eq!(res, Err(unsafe { lma.as_ptr().add(2) }));
+    assert_eq!(data, lma);
+}
+
+#[test]
+fn test_init_data_readback() {
+    let lma = [1u32, 2, 3, 4];
+    let mut data = [0u32; 4];
+    let res = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_readback(sdata, sdata.add(data.len()), lma.as_ptr())
+    };
+
+    assert_eq!(res, Ok(()));
+    assert_eq!(data, lma);
+
+    let mut data = [0u32; 4];
+    let sdata = data.as_mut_ptr();
+    let res = unsafe {
+        let mut ram = FlipBit {
+            byte: (sdata as *mut u8).add(5),
+        };
+        init_readback(&mut ram, sdata, sdata.add(4), lma.as_ptr())
+    };
+
+    assert_eq!(res, Err(unsafe { sdata.add(1) }));
+    assert_eq!(data[0], 1);
+    assert_eq!(data[2..], [0, 0]);
+}
+
+#[test]
+fn test_march_c() {
+    let mut mem = [0x1234_5678u32; 16];
+    let start = mem.as_mut_ptr();
+    let res = unsafe { march_c_test(start, start.add(mem.len())) };
+
+    assert_eq!(res, Ok(()));
+    assert_eq!(mem, [0u32; 16]);
+}
+
+#[test]
+fn test_march_c_fault() {
+    let mut mem = [0u32; 16];
+    let start = mem.as_mut_ptr();
+    let res = unsafe {
+        let faulty = start.add(5);
+        let mut flip = FlipBit {
+            byte: (faulty as *mut u8).add(2),
+        };
+        (march_c(&mut flip, start, start.add(mem.len())), faulty)
+    };
+
+    assert_eq!(res.0, Err(res.1));
+}
+
+#[test]
+fn test_init_ramfunc() {
+    let flash = [0x4770_4770u32, 0xBF00_BF00, 0xE7FE_E7FE, 0x4770_BF00];
+    let mut ram = [0u32; 4];
+    unsafe {
+        let sfunc = ram.as_mut_ptr();
+        init_ramfunc(sfunc, sfunc.add(ram.len()), flash.as_ptr());
+    }
+
+    assert_eq!(ram, flash);
+}
+
+#[test]
+fn test_zero_bss_with() {
+    let mut mem = [0xFFu8; 3 * PROGRESS_INTERVAL + 7];
+    let mut calls = 0;
+    let mut last = 0;
+    let words = unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_bss_with(sbss, sbss.add(mem.len()), |done| {
+            calls += 1;
+            last = done;
+        })
+    };
+
+    assert!(mem.iter().all(|&b| b == 0));
+    assert_eq!(words, mem.len());
+    assert_eq!(calls, 3);
+    assert_eq!(last, 3 * PROGRESS_INTERVAL);
+}
+
+#[test]
+fn test_zero_bss_fast() {
+    let mut mem = [0xFFFF_FFFFu32; 8];
+    let words = unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_bss_fast(sbss, sbss.add(mem.len()))
+    };
+
+    assert_eq!(mem, [0u32; 8]);
+    assert_eq!(words, mem.len());
+}
+
+#[test]
+fn test_init_data_regions() {
+    let sram_lma = [1u32, 2, 3, 4];
+    let ccm_lma = [5u32, 6];
+    let mut sram = [0u32; 4];
+    let mut ccm = [0u32; 2];
+    unsafe {
+        let (ssram, sccm) = (sram.as_mut_ptr(), ccm.as_mut_ptr());
+        init_data_regions(&[
+            (ssram, ssram.add(sram.len()), sram_lma.as_ptr()),
+            (sccm, sccm.add(ccm.len()), ccm_lma.as_ptr()),
+        ]);
+    }
+
+    assert_eq!(sram, sram_lma);
+    assert_eq!(ccm, ccm_lma);
+}
+
+#[test]
+fn test_len_variants() {
+    let lma = [1u16, 2, 3, 4, 5];
+    let mut by_end = [0u16; 5];
+    let mut by_len = [0u16; 5];
+    unsafe {
+        let sdata = by_end.as_mut_ptr();
+        init_data(sdata, sdata.add(by_end.len()), lma.as_ptr());
+        init_data_len(by_len.as_mut_ptr(), lma.as_ptr(), by_len.len());
+    }
+    assert_eq!(by_len, by_end);
+
+    unsafe {
+        let sbss = by_end.as_mut_ptr();
+        zero_bss(sbss, sbss.add(3));
+        zero_bss_len(by_len.as_mut_ptr(), 3);
+    }
+    assert_eq!(by_len, by_end);
+    assert_eq!(by_len, [0, 0, 0, 4, 5]);
+}
+
+#[test]
+fn test_init_data_from_const() {
+    const INITIAL: [u32; 4] = [1, 2, 0xDEAD_BEEF, !0];
+    let mut data = [0u32; 5];
+    unsafe { init_data_from_const(data.as_mut_ptr(), &INITIAL) }
+
+    assert_eq!(data, [1, 2, 0xDEAD_BEEF, !0, 0]);
+}
+
+#[test]
+fn test_zero_bss_n() {
+    let mut mem = [!0u32; 6];
+    unsafe { zero_bss_n::<u32, 4>(mem.as_mut_ptr().add(1)) }
+
+    assert_eq!(mem, [!0, 0, 0, 0, 0, !0]);
+
+    unsafe { zero_bss_n::<u32, 0>(mem.as_mut_ptr()) }
+    assert_eq!(mem[0], !0);
+}
+
+#[test]
+fn test_init_data_checksummed() {
+    let lma = [0x0102_0304u32, 0xFFFF_FFFF, 0x8000_0001];
+    let mut data = [0u32; 3];
+    let sum = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_checksummed(sdata, sdata.add(data.len()), lma.as_ptr())
+    };
+
+    assert_eq!(data, lma);
+    assert_eq!(sum, 1 + 2 + 3 + 4 + 4 * 0xFF + 0x80 + 1);
+}
+
+#[test]
+fn test_init_data_overlapping() {
+    // `.data` above its LMA
+    let mut mem = [1u32, 2, 3, 4, 5, 6, 0, 0];
+    let words = unsafe {
+        let sidata = mem.as_mut_ptr();
+        let sdata = sidata.add(2);
+        init_data_overlapping(sdata, sdata.add(6), sidata)
+    };
+
+    assert_eq!(mem, [1, 2, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(words, 6);
+
+    // `.data` below its LMA
+    let mut mem = [0u32, 0, 1, 2, 3, 4, 5, 6];
+    let words = unsafe {
+        let sdata = mem.as_mut_ptr();
+        init_data_overlapping(sdata, sdata.add(6), sdata.add(2))
+    };
+
+    assert_eq!(mem, [1, 2, 3, 4, 5, 6, 5, 6]);
+    assert_eq!(words, 6);
+}
+
+#[test]
+fn test_init_data_gen() {
+    let mut data = [0u16; 8];
+    let words = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_gen(sdata, sdata.add(data.len()), |i| 0x100 + i as u16 * 3)
+    };
+
+    assert_eq!(
+        data,
+        [0x100, 0x103, 0x106, 0x109, 0x10C, 0x10F, 0x112, 0x115]
+    );
+    assert_eq!(words, 8);
+}
+
+#[test]
+fn test_init_data_bswap() {
+    let lma = [
+        u32::from_ne_bytes([0x12, 0x34, 0x56, 0x78]),
+        u32::from_ne_bytes([0xDE, 0xAD, 0xBE, 0xEF]),
+    ];
+    let mut data = [0u32; 2];
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_bswap(sdata, sdata.add(data.len()), lma.as_ptr());
+    }
+
+    assert_eq!(
+        data,
+        [
+            u32::from_ne_bytes([0x78, 0x56, 0x34, 0x12]),
+            u32::from_ne_bytes([0xEF, 0xBE, 0xAD, 0xDE]),
+        ]
+    );
+
+    let lma = [0x0102u16, 0xA0B0];
+    let mut data = [0u16; 2];
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_bswap(sdata, sdata.add(data.len()), lma.as_ptr());
+    }
+
+    assert_eq!(data, [0x0201, 0xB0A0]);
+}
+
+#[test]
+fn test_init_data_xor() {
+    let lma = [0x1234_5678u32, 0xFFFF_0000, 0x0F0F_0F0F];
+    let mut data = [0u32; 3];
+    let fold = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_xor(sdata, sdata.add(data.len()), lma.as_ptr())
+    };
+
+    assert_eq!(data, lma);
+    assert_eq!(fold, 0xE2C4_5977);
+
+    let fold = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_xor(sdata, sdata, lma.as_ptr())
+    };
+    assert_eq!(fold, 0);
+}
+
+#[test]
+fn test_init_data_reported() {
+    let lma = *b"abcde";
+    let mut data = [0u8; 5];
+    let report = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_reported(sdata, sdata.add(data.len()), lma.as_ptr())
+    };
+
+    assert_eq!(data, lma);
+    assert_eq!(
+        report,
+        DataInitReport {
+            words: 5,
+            checksum: 0xF04F_C729,
+        }
+    );
+
+    // The checksum only depends on the bytes, not on the word size
+    let lma = [u32::from_ne_bytes(*b"abcd"), u32::from_ne_bytes(*b"efgh")];
+    let mut data = [0u32; 2];
+    let report = unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_reported(sdata, sdata.add(data.len()), lma.as_ptr())
+    };
+
+    assert_eq!(data, lma);
+    assert_eq!(
+        report,
+        DataInitReport {
+            words: 2,
+            checksum: 0xEBE1_9591,
+        }
+    );
+}
+
+#[test]
+fn test_native_word() {
+    let lma: [NativeWord; 4] = [1, 2, 3, !0];
+    let mut mem: [NativeWord; 4] = [0; 4];
+    unsafe {
+        let start = mem.as_mut_ptr();
+        assert_eq!(
+            init_data_native(start, start.add(mem.len()), lma.as_ptr()),
+            4
+        );
+    }
+    assert_eq!(mem, lma);
+
+    unsafe {
+        let start = mem.as_mut_ptr();
+        assert_eq!(zero_bss_native(start, start.add(mem.len())), 4);
+    }
+    assert_eq!(mem, [0; 4]);
+}
+
+#[cfg(feature = "wide-words")]
+#[test]
+fn test_wide_wrappers() {
+    let lma = [1u64, 2, 1 << 40, !0];
+    let mut mem = [0u64; 4];
+    unsafe {
+        let start = mem.as_mut_ptr();
+        assert_eq!(init_data64(start, start.add(mem.len()), lma.as_ptr()), 4);
+        assert_eq!(mem, lma);
+        assert_eq!(zero_bss64(start, start.add(mem.len())), 4);
+        assert_eq!(mem, [0; 4]);
+    }
+}
+
+#[test]
+fn test_narrow_wrappers() {
+    let lma = [1u16, 2, 0xFFFF];
+    let mut mem = [0u16; 3];
+    unsafe {
+        let start = mem.as_mut_ptr();
+        assert_eq!(init_data16(start, start.add(mem.len()), lma.as_ptr()), 3);
+        assert_eq!(mem, lma);
+        assert_eq!(zero_bss16(start, start.add(mem.len())), 3);
+        assert_eq!(mem, [0; 3]);
+    }
+
+    let lma = [1u8, 2, 3, 0xFF, 5];
+    let mut mem = [0u8; 5];
+    unsafe {
+        let start = mem.as_mut_ptr();
+        assert_eq!(init_data8(start, start.add(mem.len()), lma.as_ptr()), 5);
+        assert_eq!(mem, lma);
+        assert_eq!(zero_bss8(start, start.add(mem.len())), 5);
+        assert_eq!(mem, [0; 5]);
+    }
+}
+
+#[test]
+fn test_zero_bss_via() {
+    struct Recorder {
+        calls: usize,
+        range: (usize, usize),
+    }
+
+    unsafe impl BssZeroer for Recorder {
+        unsafe fn zero(&mut self, start: *mut u32, end: *mut u32) {
+            self.calls += 1;
+            self.range = (start as usize, end as usize);
+            CpuZeroer.zero(start, end);
+        }
+    }
+
+    let mut mem = [0xFFFF_FFFFu32; 8];
+    let mut recorder = Recorder {
+        calls: 0,
+        range: (0, 0),
+    };
+    let (sbss, ebss) = unsafe {
+        let sbss = mem.as_mut_ptr();
+        let ebss = sbss.add(mem.len());
+        zero_bss_via(&mut recorder, sbss, ebss);
+        (sbss as usize, ebss as usize)
+    };
+
+    assert_eq!(mem, [0u32; 8]);
+    assert_eq!(recorder.calls, 1);
+    assert_eq!(recorder.range, (sbss, ebss));
+}
+
+#[test]
+fn test_mem_filler() {
+    struct Recorder {
+        calls: usize,
+        last: (usize, usize, u8),
+    }
+
+    unsafe impl MemFiller for Recorder {
+        unsafe fn fill(&mut self, start: *mut u8, len: usize, byte: u8) {
+            self.calls += 1;
+            self.last = (start as usize, len, byte);
+            CpuFiller.fill(start, len, byte);
+        }
+    }
+
+    let mut recorder = Recorder {
+        calls: 0,
+        last: (0, 0, 0),
+    };
+    let mut mem = [!0u32; 4];
+    let start = mem.as_mut_ptr();
+    unsafe { zero_bss_with_filler(&mut recorder, start, start.add(4)) }
+
+    assert_eq!(mem, [0; 4]);
+    assert_eq!(recorder.calls, 1);
+    assert_eq!(recorder.last, (start as usize, 16, 0));
+
+    let mut bytes = [0u8; 6];
+    let start = bytes.as_mut_ptr();
+    unsafe { fill_with_filler(&mut recorder, start.add(1), start.add(5), 0xFF) }
+
+    assert_eq!(bytes, [0, 0xFF, 0xFF, 0xFF, 0xFF, 0]);
+    assert_eq!(recorder.calls, 2);
+    assert_eq!(recorder.last, (start as usize + 1, 4, 0xFF));
+}
+
+#[test]
+fn test_init_data_committed() {
+    struct Recorder {
+        written: usize,
+        committed: usize,
+    }
+
+    unsafe impl Committer for Recorder {
+        unsafe fn write(&mut self, dst: *mut u8, src: *const u8, len: usize) {
+            assert_eq!(self.committed, 0);
+            self.written += len;
+            RamCommitter.write(dst, src, len);
+        }
+
+        unsafe fn commit(&mut self) {
+            // The whole section must have been written before the commit
+            assert_eq!(self.written, 5);
+            self.committed += 1;
+        }
+    }
+
+    let lma = [1u8, 2, 3, 4, 5];
+    let mut data = [0u8; 5];
+    let mut recorder = Recorder {
+        written: 0,
+        committed: 0,
+    };
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_committed(&mut recorder, sdata, sdata.add(5), lma.as_ptr());
+    }
+
+    assert_eq!(data, lma);
+    assert_eq!(recorder.committed, 1);
+}
+
+#[test]
+fn test_init_data_bytes() {
+    // `u32` arrays provide the 4-byte alignment
+    let lma = [
+        0x0403_0201u32.to_le(),
+        0x0807_0605u32.to_le(),
+        0x0C0B_0A09u32.to_le(),
+    ];
+    let mut data = [0u32; 3];
+    unsafe {
+        let sdata = data.as_mut_ptr() as *mut u8;
+        init_data_bytes(sdata, sdata.add(11), lma.as_ptr() as *const u8);
+    }
+
+    assert_eq!(data[..2], lma[..2]);
+    assert_eq!(data[2], 0x000B_0A09u32.to_le());
+}
+
+#[test]
+fn test_init_data_split() {
+    let bank_a = [1u8, 2, 3];
+    let bank_b = [4u8, 5, 6, 7, 8];
+    let mut data = [0u8; 7];
+    let sdata = data.as_mut_ptr();
+    unsafe {
+        init_data_split(sdata, sdata.add(7), (bank_a.as_ptr(), 3), bank_b.as_ptr());
+    }
+    assert_eq!(data, [1, 2, 3, 4, 5, 6, 7]);
+
+    // The first piece covers the whole section
+    let mut data = [0u8; 2];
+    let sdata = data.as_mut_ptr();
+    unsafe {
+        init_data_split(sdata, sdata.add(2), (bank_a.as_ptr(), 3), bank_b.as_ptr());
+    }
+    assert_eq!(data, [1, 2]);
+}
+
+#[test]
+fn test_region_words() {
+    let mem = [0u32; 8];
+    let start = mem.as_ptr();
+    unsafe {
+        assert_eq!(region_words(start, start), 0);
+        assert_eq!(region_words(start, start.add(1)), 1);
+        assert_eq!(region_words(start, start.add(mem.len())), 8);
+        assert_eq!(region_words(start.add(3), start.add(mem.len())), 5);
+    }
+
+    let mem = [0u16; 4];
+    let start = mem.as_ptr();
+    unsafe {
+        assert_eq!(region_words(start, start.add(mem.len())), 4);
+    }
+}
+
+#[test]
+fn test_zero_bss_scrubbed() {
+    let mut mem = [0x1234_5678u32; 16];
+    let res = unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_bss_scrubbed(sbss, sbss.add(mem.len()))
+    };
+
+    assert_eq!(res, Ok(()));
+    assert_eq!(mem, [0u32; 16]);
+}
+
+#[test]
+fn test_zero_bss_scrubbed_fault() {
+    let mut mem = [0u32; 16];
+    let sbss = mem.as_mut_ptr();
+    let res = unsafe {
+        let faulty = sbss.add(9);
+        let mut flip = FlipBit {
+            byte: faulty as *mut u8,
+        };
+        (scrub(&mut flip, sbss, sbss.add(mem.len())), faulty)
+    };
+
+    assert_eq!(res.0, Err(res.1));
+}
+
+#[test]
+fn test_init_with() {
+    let mut mem = [0u32; 8];
+    unsafe {
+        let start = mem.as_mut_ptr();
+        init_with(start, start.add(mem.len()), |i| i as u32);
+    }
+
+    assert_eq!(mem, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_init_data_aligned() {
+    // `u64` arrays provide 8-byte alignment
+    let lma = [0x0123_4567_89AB_CDEFu64, 0xFEDC_BA98_7654_3210];
+
+    unsafe fn check<const ALIGN: usize>(lma: &[u64; 2]) {
+        let mut data = [0u64; 2];
+        let sdata = data.as_mut_ptr() as *mut u8;
+        init_data_aligned::<ALIGN>(sdata, sdata.add(16), lma.as_ptr() as *const u8);
+        assert_eq!(data, *lma);
+    }
+
+    unsafe {
+        check::<1>(&lma);
+        check::<2>(&lma);
+        check::<4>(&lma);
+        check::<8>(&lma);
+    }
+}
+
+#[test]
+fn test_zero_bss_auto() {
+    unsafe fn check(start: usize, end: usize) {
+        // `u64` arrays provide 8-byte alignment
+        let mut mem = [!0u64; 4];
+        let base = mem.as_mut_ptr() as *mut u8;
+        zero_bss_auto(base.add(start), base.add(end));
+
+        for i in 0..32 {
+            let expected = if start <= i && i < end { 0 } else { 0xFF };
+            assert_eq!(*base.add(i), expected, "byte {} of {}..{}", i, start, end);
+        }
+    }
+
+    unsafe {
+        check(1, 6);
+        check(2, 14);
+        check(4, 28);
+        check(8, 24);
+        check(0, 32);
+    }
+}
+
+#[test]
+fn test_verify_zeroed() {
+    let mut mem = [0u32; 8];
+    let start = mem.as_ptr();
+    let res = unsafe { verify_zeroed(start, start.add(mem.len())) };
+    assert_eq!(res, Ok(()));
+
+    mem[5] = 0x0000_0100;
+    let start = mem.as_ptr();
+    let res = unsafe { (verify_zeroed(start, start.add(mem.len())), start.add(5)) };
+    assert_eq!(res.0, Err(res.1));
+}
+
+#[test]
+fn test_count_nonzero() {
+    let mem = [0u32, 1, 0, 0x8000_0000, 0, 0, !0, 0];
+    let start = mem.as_ptr();
+    unsafe {
+        assert_eq!(count_nonzero(start, start.add(mem.len())), 3);
+        assert_eq!(count_nonzero(start, start.add(1)), 0);
+    }
+}
+
+#[test]
+fn test_poison() {
+    const POISON: u32 = 0xBAD0_BAD0;
+    let lma = [1u32, 2, 3, 4, 5, 6];
+    let mut mem = [0u32; 6];
+    let start = mem.as_mut_ptr();
+    unsafe {
+        poison(start, start.add(6), POISON);
+        assert_eq!(
+            assert_unpoisoned(start, start.add(6), POISON),
+            Err(start as *const _)
+        );
+
+        // Fully overwritten
+        init_data(start, start.add(6), lma.as_ptr());
+        assert_eq!(assert_unpoisoned(start, start.add(6), POISON), Ok(()));
+
+        // Partially overwritten
+        poison(start, start.add(6), POISON);
+        init_data(start, start.add(4), lma.as_ptr());
+        assert_eq!(
+            assert_unpoisoned(start, start.add(6), POISON),
+            Err(start.add(4) as *const _)
+        );
+    }
+}
+
+#[test]
+fn test_data_matches_lma() {
+    let lma = [1u32, 2, 3, 4];
+    let mut data = [0u32; 4];
+    init_slice_from(&mut data, &lma);
+    let sdata = data.as_ptr();
+    unsafe {
+        assert!(data_matches_lma(sdata, sdata.add(4), lma.as_ptr()));
+    }
+
+    data[2] ^= 1 << 7;
+    let sdata = data.as_ptr();
+    unsafe {
+        assert!(!data_matches_lma(sdata, sdata.add(4), lma.as_ptr()));
+        assert!(data_matches_lma(sdata, sdata.add(2), lma.as_ptr()));
+    }
+}
+
+#[repr(align(256))]
+struct VectorTable([u32; 48]);
+
+#[test]
+fn test_init_vector_table() {
+    let mut flash = [0u32; 48];
+    for (i, vector) in flash.iter_mut().enumerate() {
+        *vector = 0x0800_0000 + 2 * i as u32 + 1;
+    }
+    let mut ram = VectorTable([0; 48]);
+    unsafe {
+        init_vector_table(ram.0.as_mut_ptr(), flash.as_ptr(), flash.len());
+    }
+
+    assert_eq!(ram.0[..], flash[..]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "`dst` is not aligned for a vector table")]
+fn test_init_vector_table_misaligned() {
+    let flash = [0u32; 48];
+    let mut ram = VectorTable([0; 48]);
+    unsafe {
+        // 128-byte aligned, but a 48 entry table requires 256 bytes
+        init_vector_table(ram.0.as_mut_ptr().add(32), flash.as_ptr(), 16);
+        init_vector_table(ram.0.as_mut_ptr().add(32), flash.as_ptr(), flash.len());
+    }
+}
+
+#[cfg(feature = "log")]
+mod log_output {
+    extern crate std;
+
+    use core::ptr;
+    use std::boxed::Box;
+    use std::format;
+    use std::string::String;
+    use std::sync::{Mutex, Once};
+    use std::vec::Vec;
+
+    use crate::{init_data, init_data_bytes, init_data_split, zero_bss, zero_bss_except};
+
+    // NOTE `Mutex::new` is only `const` from Rust 1.63, above this crate's MSRV, so the records
+    // are allocated on first use instead
+    static INIT: Once = Once::new();
+    static mut RECORDS: *const Mutex<Vec<String>> = ptr::null();
+
+    fn records() -> &'static Mutex<Vec<String>> {
+        // NOTE(unsafe) `RECORDS` is only written once, by `INIT`, which completes before it is read
+        unsafe {
+            INIT.call_once(|| RECORDS = Box::leak(Box::new(Mutex::new(Vec::new()))));
+            &*RECORDS
+        }
+    }
+
+    struct Capture;
+
+    impl log::Log for Capture {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            records().lock().unwrap().push(format!("{}", record.args()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn test_log_output() {
+        static CAPTURE: Capture = Capture;
+        let _ = log::set_logger(&CAPTURE);
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let mut data = [0u32; 4];
+        let lma = [1u32, 2, 3, 4];
+        let mut bss = [1u16; 3];
+        let mut bytes = [0u32; 3];
+        let mut split = [0u8; 5];
+        let mut except = [1u32; 6];
+        // Other tests may log concurrently, so only the records of this test's regions count
+        let skip = records().lock().unwrap().len();
+        unsafe {
+            let sdata = data.as_mut_ptr();
+            init_data(sdata, sdata.add(data.len()), lma.as_ptr());
+            let sbss = bss.as_mut_ptr();
+            zero_bss(sbss, sbss.add(bss.len()));
+
+            // Sections initialized in several pieces are still reported once
+            let sdata = bytes.as_mut_ptr() as *mut u8;
+            init_data_bytes(sdata, sdata.add(11), lma.as_ptr() as *const u8);
+            let sdata = split.as_mut_ptr();
+            let src = lma.as_ptr() as *const u8;
+            init_data_split(sdata, sdata.add(5), (src, 2), src.add(8));
+            let sbss = except.as_mut_ptr();
+            zero_bss_except(sbss, sbss.add(6), sbss.add(1), sbss.add(3));
+        }
+
+        let records = records().lock().unwrap();
+        let regions = [
+            (data.as_ptr() as usize, 16),
+            (bss.as_ptr() as usize, 6),
+            (bytes.as_ptr() as usize, 12),
+            (split.as_ptr() as usize, 5),
+            (except.as_ptr() as usize, 24),
+        ];
+        for &(start, len) in &regions {
+            // Every record that lands in the region counts, including those of any piece of it
+            let count = records[skip..]
+                .iter()
+                .filter_map(|r| r.split(" at 0x").nth(1)?.split(' ').next())
+                .filter_map(|addr| usize::from_str_radix(addr, 16).ok())
+                .filter(|&addr| start <= addr && addr < start + len)
+                .count();
+            assert_eq!(count, 1, "{:?}", records);
+        }
+
+        let expected = [
+            format!("r0: initialized .data at {:p} (16 bytes)", data.as_ptr()),
+            format!("r0: zeroed .bss at {:p} (6 bytes)", bss.as_ptr()),
+            format!("r0: initialized .data at {:p} (11 bytes)", bytes.as_ptr()),
+            format!("r0: initialized .data at {:p} (5 bytes)", split.as_ptr()),
+            format!("r0: zeroed .bss at {:p} (16 bytes)", except.as_ptr()),
+        ];
+        for record in &expected {
+            assert!(records[skip..].contains(record), "{:?}", records);
+        }
+    }
+}
+
+#[test]
+fn test_ram_initializer() {
+    let lma = [1u32, 2, 3, 4];
+    let code = [0x4770_4770u32; 2];
+    let mut bss = [0xFFFF_FFFFu32; 8];
+    let mut data = [0u32; 4];
+    let mut ramfunc = [0u32; 2];
+    unsafe {
+        let (sbss, sdata, sfunc) = (bss.as_mut_ptr(), data.as_mut_ptr(), ramfunc.as_mut_ptr());
+        let mut init = RamInitializer::new();
+        init.bss(sbss, sbss.add(bss.len()))
+            .unwrap()
+            .data(sdata, sdata.add(data.len()), lma.as_ptr())
+            .unwrap()
+            .ramfunc(sfunc, sfunc.add(ramfunc.len()), code.as_ptr())
+            .unwrap();
+        init.run();
+    }
+
+    assert_eq!(bss, [0u32; 8]);
+    assert_eq!(data, lma);
+    assert_eq!(ramfunc, code);
+}
+
+#[test]
+fn test_ram_initializer_capacity() {
+    let mut bss = [0u32; 1];
+    let sbss = bss.as_mut_ptr();
+    let mut init = RamInitializer::new();
+    for _ in 0..RAM_INITIALIZER_CAPACITY {
+        assert!(init.bss(sbss, sbss).is_ok());
+    }
+    assert_eq!(init.bss(sbss, sbss).err(), Some(CapacityError));
+    // The sections of each kind have their own capacity
+    assert!(init.data(sbss, sbss, sbss).is_ok());
+}
+
+#[test]
+fn test_init_data_wide() {
+    let mut lma = [0u8; 32];
+    for (i, byte) in lma.iter_mut().enumerate() {
+        *byte = i as u8 + 1;
+    }
+
+    for &(start, end) in &[(0, 32), (3, 32), (3, 29), (5, 8), (8, 8)] {
+        // `u64` arrays provide 8-byte alignment
+        let mut data = [0u64; 4];
+        unsafe {
+            let base = data.as_mut_ptr() as *mut u8;
+            init_data_wide(base.add(start), base.add(end), lma.as_ptr().add(start));
+        }
+
+        let bytes = unsafe { core::slice::from_raw_parts(data.as_ptr() as *const u8, 32) };
+        assert!(bytes[..start].iter().all(|&b| b == 0));
+        assert_eq!(bytes[start..end], lma[start..end]);
+        assert!(bytes[end..].iter().all(|&b| b == 0));
+    }
+}
+
+#[test]
+fn test_init_data_opt() {
+    // `u64` arrays provide 8-byte alignment
+    unsafe fn check(dst: usize, src: usize, len: usize) {
+        let mut lma = [0u64; 6];
+        let mut data = [0u64; 6];
+        let (lma, data) = (lma.as_mut_ptr() as *mut u8, data.as_mut_ptr() as *mut u8);
+        for i in 0..48 {
+            *lma.add(i) = i as u8 + 1;
+        }
+
+        init_data_opt(data.add(dst), data.add(dst + len), lma.add(src));
+
+        for i in 0..48 {
+            let expected = if dst <= i && i < dst + len {
+                (i - dst + src) as u8 + 1
+            } else {
+                0
+            };
+            assert_eq!(*data.add(i), expected, "byte {} of {}+{}", i, dst, len);
+        }
+    }
+
+    unsafe {
+        // Aligned
+        check(0, 0, 40);
+        check(8, 16, 24);
+        // Misaligned head
+        check(3, 3, 37);
+        check(5, 13, 30);
+        // Misaligned tail
+        check(0, 8, 29);
+        check(8, 0, 13);
+        // Different offsets
+        check(1, 2, 40);
+        // Shorter than the head
+        check(1, 1, 3);
+        check(0, 0, 0);
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "`sdata -> edata` overlaps `sidata -> ...`")]
+fn test_init_data_overlap() {
+    let mut mem = [0u32; 8];
+    unsafe {
+        let sdata = mem.as_mut_ptr();
+        init_data(sdata, sdata.add(4), sdata.add(2));
+    }
+}
+
+#[test]
+fn test_zero_bss_banked() {
+    let mut sram1 = [0xFFFF_FFFFu32; 8];
+    let mut sram2 = [0xFFFF_FFFFu32; 4];
+    let mut ccm = [0xFFFF_FFFFu32; 2];
+    unsafe {
+        let (s1, s2, s3) = (sram1.as_mut_ptr(), sram2.as_mut_ptr(), ccm.as_mut_ptr());
+        zero_bss_banked(&[
+            (s1, s1.add(sram1.len())),
+            (s2, s2.add(sram2.len())),
+            (s3, s3.add(ccm.len())),
+        ]);
+    }
+
+    assert_eq!(sram1, [0; 8]);
+    assert_eq!(sram2, [0; 4]);
+    assert_eq!(ccm, [0; 2]);
+}
+
+#[cfg(feature = "allow-memset")]
+#[test]
+fn test_zero_bss_allow_memset() {
+    let mut mem = [0xFFu8; 4096];
+    let words = unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_bss(sbss, sbss.add(mem.len()))
+    };
+
+    assert!(mem.iter().all(|&b| b == 0));
+    assert_eq!(words, mem.len());
+}
+
+#[test]
+fn test_init_data_volatile_src() {
+    // Stands in for a memory-mapped flash window
+    let qspi = [0xDEAD_BEEFu32, 0x0BAD_F00D, 0xCAFE_BABE, 0x8BAD_F00D];
+    let mut data = [0u32; 4];
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        init_data_volatile_src(sdata, sdata.add(data.len()), qspi.as_ptr());
+    }
+
+    assert_eq!(data, qspi);
+}
+
+#[test]
+fn test_zero_gap() {
+    // `.data` in words 0..4, padding in 4..6 and `.bss` in 6..8
+    let mut ram = [0xFFFF_FFFFu32; 8];
+    unsafe {
+        let base = ram.as_mut_ptr();
+        zero_gap(base.add(4), base.add(6));
+    }
+
+    assert_eq!(ram[..4], [0xFFFF_FFFF; 4]);
+    assert_eq!(ram[4..6], [0; 2]);
+    assert_eq!(ram[6..], [0xFFFF_FFFF; 2]);
+}
+
+#[test]
+fn test_zero_bss_except() {
+    // Retention RAM in words 3..5
+    let mut ram = [0xFFFF_FFFFu32; 8];
+    let words = unsafe {
+        let base = ram.as_mut_ptr();
+        zero_bss_except(base, base.add(8), base.add(3), base.add(5))
+    };
+
+    assert_eq!(words, 6);
+    assert_eq!(ram[..3], [0; 3]);
+    assert_eq!(ram[3..5], [0xFFFF_FFFF; 2]);
+    assert_eq!(ram[5..], [0; 3]);
+
+    // The hole can be empty, or cover the whole section
+    let mut ram = [0xFFFF_FFFFu32; 4];
+    unsafe {
+        let base = ram.as_mut_ptr();
+        assert_eq!(zero_bss_except(base, base.add(4), base, base.add(4)), 0);
+        assert_eq!(ram, [0xFFFF_FFFF; 4]);
+        assert_eq!(
+            zero_bss_except(base, base.add(4), base.add(2), base.add(2)),
+            4
+        );
+    }
+    assert_eq!(ram, [0; 4]);
+}
+
+#[test]
+#[should_panic(expected = "`skip_start -> skip_end` is not within `sbss -> ebss`")]
+fn test_zero_bss_except_outside() {
+    let mut ram = [0xFFFF_FFFFu32; 8];
+    unsafe {
+        let base = ram.as_mut_ptr();
+        zero_bss_except(base, base.add(4), base.add(3), base.add(5));
+    }
+}
+
+#[test]
+fn test_init_all() {
+    let lma = [1u32, 2, 3, 4];
+    let mut bss = [0xFFFF_FFFFu32; 4];
+    let mut data = [0u32; 4];
+    let res = unsafe {
+        let (sbss, sdata) = (bss.as_mut_ptr(), data.as_mut_ptr());
+        init_all(
+            (sbss, sbss.add(bss.len())),
+            (sdata, sdata.add(data.len()), lma.as_ptr()),
+        )
+    };
+
+    assert_eq!(res, Ok(()));
+    assert_eq!(bss, [0; 4]);
+    assert_eq!(data, lma);
+}
+
+#[test]
+fn test_init_all_adjacent() {
+    // `.data` in words 0..4 and `.bss` right after it, in words 4..8
+    let lma = [1u32, 2, 3, 4];
+    let mut ram = [0xFFFF_FFFFu32; 8];
+    let res = unsafe {
+        let base = ram.as_mut_ptr();
+        init_all(
+            (base.add(4), base.add(8)),
+            (base, base.add(4), lma.as_ptr()),
+        )
+    };
+
+    assert_eq!(res, Ok(()));
+    assert_eq!(ram, [1, 2, 3, 4, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_init_all_errors() {
+    // offsets into a single buffer, so that the regions can be measured
+    let mut mem = [0u32; 0x3010 / 4];
+    let base = mem.as_mut_ptr() as *mut u8;
+    let at = |offset: usize| unsafe { base.add(offset) as *mut u32 };
+    let check = |bss: (usize, usize), data: (usize, usize, usize)| unsafe {
+        init_all((at(bss.0), at(bss.1)), (at(data.0), at(data.1), at(data.2)))
+    };
+    let data = (0x2000, 0x2010, 0x3000);
+
+    assert_eq!(check((0x1001, 0x1010), data), Err(InitError::Unaligned));
+    assert_eq!(check((0x1000, 0x1011), data), Err(InitError::Unaligned));
+    assert_eq!(check((0x1010, 0x1000), data), Err(InitError::BadRange));
+
+    let bss = (0x1000, 0x1010);
+    assert_eq!(
+        check(bss, (0x2001, 0x2010, 0x3000)),
+        Err(InitError::Unaligned)
+    );
+    assert_eq!(
+        check(bss, (0x2000, 0x2010, 0x3003)),
+        Err(InitError::Unaligned)
+    );
+    assert_eq!(
+        check(bss, (0x2010, 0x2000, 0x3000)),
+        Err(InitError::BadRange)
+    );
+    assert_eq!(
+        check(bss, (0x2000, 0x2010, 0x2004)),
+        Err(InitError::Overlap)
+    );
+
+    // `.bss` overlapping `.data` or its LMA
+    assert_eq!(check((0x1000, 0x2004), data), Err(InitError::Overlap));
+    assert_eq!(check((0x200C, 0x2020), data), Err(InitError::Overlap));
+    assert_eq!(check((0x3008, 0x3010), data), Err(InitError::Overlap));
+}
+
+#[test]
+fn test_stack_guard() {
+    let mut stack = [0u32; 16];
+    let guard = stack.as_mut_ptr();
+    unsafe {
+        install_stack_guard(guard, 0xDEAD_C0DE);
+        assert!(check_stack_guard(guard, 0xDEAD_C0DE));
+        assert!(!check_stack_guard(guard, 0xC0DE_C0DE));
+
+        // Simulate a stack overflow clobbering the canary
+        *guard = 0x2000_1000;
+        assert!(!check_stack_guard(guard, 0xDEAD_C0DE));
+    }
+}
+
+#[test]
+fn test_init_data_lz4() {
+    // "ABC" + 15 byte match at offset 3, then the literals "hello"
+    let block = [
+        0x3B, b'A', b'B', b'C', 0x03, 0x00, //
+        0x50, b'h', b'e', b'l', b'l', b'o',
+    ];
+    let mut data = [0u8; 23];
+    unsafe {
+        init_data_lz4(data.as_mut_ptr(), data.len(), block.as_ptr());
+    }
+    assert_eq!(data[..], b"ABCABCABCABCABCABChello"[..]);
+
+    // 16 literals and a 40 byte match, both using extra length bytes, then the literal "!"
+    let mut block = [0u8; 23];
+    block[..2].copy_from_slice(&[0xFF, 1]);
+    block[2..18].copy_from_slice(b"0123456789abcdef");
+    block[18..].copy_from_slice(&[16, 0, 21, 0x10, b'!']);
+    let mut expected = [0u8; 57];
+    for (i, byte) in expected[..56].iter_mut().enumerate() {
+        *byte = b"0123456789abcdef"[i % 16];
+    }
+    expected[56] = b'!';
+    let mut data = [0u8; 57];
+    unsafe {
+        init_data_lz4(data.as_mut_ptr(), data.len(), block.as_ptr());
+    }
+    assert_eq!(data[..], expected[..]);
+}
+
+#[test]
+fn test_apply_data_delta() {
+    let mut data = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    let delta = [
+        0x01, 2, 0, // SKIP 2
+        0x02, 3, 0, 0xA, 0xB, 0xC, // COPY 3
+        0x01, 1, 0, // SKIP 1
+        0x02, 1, 0, 0xD,  // COPY 1
+        0x00, // END
+    ];
+    unsafe {
+        apply_data_delta(data.as_mut_ptr(), data.len(), delta.as_ptr());
+    }
+    assert_eq!(data, [1, 2, 0xA, 0xB, 0xC, 6, 0xD, 8]);
+
+    // Opcodes that extend past the end of the section are truncated
+    let mut data = [0u8; 4];
+    unsafe {
+        apply_data_delta(data.as_mut_ptr(), data.len(), delta.as_ptr());
+    }
+    assert_eq!(data, [0, 0, 0xA, 0xB]);
+}
+
+#[test]
+#[allow(clippy::let_unit_value)]
+fn test_words_not_zero_sized() {
+    let () = AssertNotZeroSized::<u8>::OK;
+    let () = AssertNotZeroSized::<u16>::OK;
+    let () = AssertNotZeroSized::<u32>::OK;
+    let () = AssertNotZeroSized::<usize>::OK;
+    let () = AssertNotZeroSized::<isize>::OK;
+    #[cfg(feature = "wide-words")]
+    {
+        let () = AssertNotZeroSized::<u64>::OK;
+        let () = AssertNotZeroSized::<u128>::OK;
+    }
+}
+
+#[test]
+fn test_word_zero() {
+    fn is_zero<T>() -> bool
+    where
+        T: Word,
+    {
+        bytes_of(&T::ZERO).iter().all(|&byte| byte == 0)
+    }
+
+    assert!(is_zero::<u8>());
+    assert!(is_zero::<u16>());
+    assert!(is_zero::<u32>());
+    assert!(is_zero::<usize>());
+    assert!(is_zero::<isize>());
+}
+
+#[test]
+fn test_narrow_words() {
+    fn roundtrip<T>(lma: [T; 4], zero: T)
+    where
+        T: Word + PartialEq + core::fmt::Debug,
+    {
+        let mut data = [zero; 4];
+        init_slice_from(&mut data, &lma);
+        assert_eq!(data, lma);
+        zero_slice(&mut data);
+        assert_eq!(data, [zero; 4]);
+    }
+
+    roundtrip::<u8>([1, 2, 3, 0xFF], 0);
+    roundtrip::<u16>([1, 2, 3, 0xFFFF], 0);
+    roundtrip::<u32>([1, 2, 3, 0xFFFF_FFFF], 0);
+}
+
+#[cfg(feature = "wide-words")]
+#[test]
+fn test_wide_words() {
+    let lma = [1u128 << 100, 2, 3, !0];
+    let mut data = [0u128; 4];
+    init_slice_from(&mut data, &lma);
+
+    assert_eq!(data, lma);
+}
+
+#[cfg(feature = "debug-prefill")]
+#[test]
+fn test_zero_bss_prefill() {
+    let mut mem = [0x1234_5678u32; 8];
+    let words = unsafe {
+        let sbss = mem.as_mut_ptr();
+        zero_bss(sbss, sbss.add(mem.len()))
+    };
+
+    assert_eq!(mem, [0; 8]);
+    assert_eq!(words, mem.len());
+}
+
+// On the host, `cortex-m-asm` must leave `zero_bss` on the portable loop
+#[cfg(feature = "cortex-m-asm")]
+#[test]
+fn test_zero_bss_asm_fallback() {
+    let mut bytes = [0xFFu8; 7];
+    let mut words = [0xFFFF_FFFFu32; 7];
+    unsafe {
+        let sbss = bytes.as_mut_ptr();
+        assert_eq!(zero_bss(sbss, sbss.add(bytes.len())), bytes.len());
+        let sbss = words.as_mut_ptr();
+        assert_eq!(zero_bss(sbss, sbss.add(words.len())), words.len());
+    }
+
+    assert_eq!(bytes, [0; 7]);
+    assert_eq!(words, [0; 7]);
+}
+
+// `inline` must not change the behavior of the functions it inlines
+#[cfg(feature = "inline")]
+#[test]
+fn test_inline() {
+    let lma = [1u32, 2, 3, 4];
+    let mut data = [0u32; 4];
+    let mut bss = [!0u32; 4];
+    unsafe {
+        let sdata = data.as_mut_ptr();
+        assert_eq!(init_data(sdata, sdata.add(data.len()), lma.as_ptr()), 4);
+        let sbss = bss.as_mut_ptr();
+        assert_eq!(zero_bss(sbss, sbss.add(bss.len())), 4);
+    }
+
+    assert_eq!(data, lma);
+    assert_eq!(bss, [0; 4]);
+}
+
+// Generative tests: run the primitives over every region length from 0 to 256 words, at random
+// offsets and over random memory contents, and check every byte of the backing buffer
+
+/// Xorshift32 pseudo-random number generator, seeded with a fixed value for reproducibility.
+struct XorShift(u32);
+
+impl XorShift {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+
+    fn fill(&mut self, bytes: &mut [u8]) {
+        for byte in bytes {
+            *byte = self.next() as u8;
+        }
+    }
+}
+
+const GEN_MAX_WORDS: usize = 256;
+const GEN_BYTES: usize = (GEN_MAX_WORDS + 8) * 8;
+
+/// Lengths to test; Miri is too slow to go through all of them.
+fn gen_lengths() -> impl Iterator<Item = usize> {
+    (0..=GEN_MAX_WORDS).filter(|&len| !cfg!(miri) || len % 64 == 0)
+}
+
+#[repr(align(8))]
+struct GenBuffer([u8; GEN_BYTES]);
+
+/// Checks that `buf` equals `before`, except for `[start, end)` which must equal `inside`.
+fn check_region(buf: &[u8], before: &[u8], start: usize, end: usize, inside: &[u8], what: &str) {
+    for (i, (&byte, &old)) in buf.iter().zip(before).enumerate() {
+        let expected = if start <= i && i < end {
+            inside[i - start]
+        } else {
+            old
+        };
+        assert_eq!(
+            byte, expected,
+            "{}: byte {} of [{}, {})",
+            what, i, start, end
+        );
+    }
+}
+
+fn gen_zero_bss<T>(rng: &mut XorShift)
+where
+    T: Word,
+{
+    let size = mem::size_of::<T>();
+    let zeros = [0u8; GEN_BYTES];
+    let mut mem = GenBuffer([0; GEN_BYTES]);
+    for len in gen_lengths() {
+        rng.fill(&mut mem.0);
+        let before = mem.0;
+        let offset = rng.next() as usize % 8;
+        let words = unsafe {
+            let sbss = (mem.0.as_mut_ptr() as *mut T).add(offset);
+            zero_bss(sbss, sbss.add(len))
+        };
+
+        assert_eq!(words, len);
+        let start = offset * size;
+        check_region(
+            &mem.0,
+            &before,
+            start,
+            start + len * size,
+            &zeros,
+            "zero_bss",
+        );
+    }
+}
+
+fn gen_init_data<T>(rng: &mut XorShift)
+where
+    T: Word,
+{
+    let size = mem::size_of::<T>();
+    let mut lma = GenBuffer([0; GEN_BYTES]);
+    let mut mem = GenBuffer([0; GEN_BYTES]);
+    for len in gen_lengths() {
+        rng.fill(&mut lma.0);
+        rng.fill(&mut mem.0);
+        let before = mem.0;
+        let (dst, src) = (rng.next() as usize % 8, rng.next() as usize % 8);
+        let words = unsafe {
+            let sdata = (mem.0.as_mut_ptr() as *mut T).add(dst);
+            let sidata = (lma.0.as_ptr() as *const T).add(src);
+            init_data(sdata, sdata.add(len), sidata)
+        };
+
+        assert_eq!(words, len);
+        let start = dst * size;
+        check_region(
+            &mem.0,
+            &before,
+            start,
+            start + len * size,
+            &lma.0[src * size..],
+            "init_data",
+        );
+    }
+}
+
+#[test]
+fn test_zero_bss_generated() {
+    let mut rng = XorShift(0x2545_F491);
+    gen_zero_bss::<u8>(&mut rng);
+    gen_zero_bss::<u16>(&mut rng);
+    gen_zero_bss::<u32>(&mut rng);
+    gen_zero_bss::<usize>(&mut rng);
+    #[cfg(feature = "wide-words")]
+    gen_zero_bss::<u64>(&mut rng);
+}
+
+#[test]
+fn test_init_data_generated() {
+    let mut rng = XorShift(0x9E37_79B9);
+    gen_init_data::<u8>(&mut rng);
+    gen_init_data::<u16>(&mut rng);
+    gen_init_data::<u32>(&mut rng);
+    gen_init_data::<usize>(&mut rng);
+    #[cfg(feature = "wide-words")]
+    gen_init_data::<u64>(&mut rng);
+}
+
+// The byte-pointer entry points handle any alignment, so they get arbitrary byte offsets
+#[test]
+fn test_unaligned_generated() {
+    let mut rng = XorShift(0x1B87_3593);
+    let zeros = [0u8; GEN_BYTES];
+    let mut lma = GenBuffer([0; GEN_BYTES]);
+    let mut mem = GenBuffer([0; GEN_BYTES]);
+    for len in gen_lengths() {
+        rng.fill(&mut lma.0);
+        rng.fill(&mut mem.0);
+        let before = mem.0;
+        let (dst, src) = (rng.next() as usize % 64, rng.next() as usize % 64);
+        unsafe {
+            let sbss = mem.0.as_mut_ptr().add(dst);
+            zero_bss_auto(sbss, sbss.add(len));
+        }
+        check_region(&mem.0, &before, dst, dst + len, &zeros, "zero_bss_auto");
+
+        let before = mem.0;
+        unsafe {
+            let sdata = mem.0.as_mut_ptr().add(dst);
+            init_data_opt(sdata, sdata.add(len), lma.0.as_ptr().add(src));
+        }
+        check_region(
+            &mem.0,
+            &before,
+            dst,
+            dst + len,
+            &lma.0[src..],
+            "init_data_opt",
+        );
+    }
 }